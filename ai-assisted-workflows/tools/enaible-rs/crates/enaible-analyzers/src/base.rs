@@ -1,6 +1,9 @@
 use anyhow::Result;
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
@@ -17,12 +20,29 @@ pub struct AnalyzerConfig {
     pub skip_patterns: HashSet<String>,
     pub gitignore_patterns: Vec<String>,
     pub exclude_globs: HashSet<String>,
+    /// Glob patterns a file must match to be collected, in addition to
+    /// `code_extensions`. Also narrows where `collect_files` starts walking:
+    /// the traversal root becomes the deepest directory every pattern's
+    /// literal prefix shares, instead of the full `target_path`. Empty means
+    /// no narrowing and no extra filtering, same as before this field existed.
+    pub include_globs: HashSet<String>,
+    /// Honor nested `.gitignore`/`.ignore` files (and negation rules) via
+    /// `ignore::WalkBuilder` instead of the flat `glob::Pattern` matching
+    /// `gitignore_patterns` does. Defaults to `true`; set to `false` to fall
+    /// back to the manual `gitignore_patterns`/`skip_patterns` matching for
+    /// callers that depend on the old flat-glob behavior.
+    pub respect_gitignore: bool,
 
     // Analysis settings
     pub max_files: Option<usize>,
     pub max_file_size_mb: usize,
     pub batch_size: usize,
     pub timeout_seconds: Option<u64>,
+    /// Directory, relative to `target_path`, where `FindingCache` persists
+    /// its content-hash index so repeated runs over a mostly-unchanged tree
+    /// can skip re-analyzing files whose content and effective config
+    /// haven't changed.
+    pub finding_cache_path: String,
 
     // Severity thresholds
     pub severity_thresholds: HashMap<String, f64>,
@@ -65,10 +85,13 @@ impl Default for AnalyzerConfig {
             skip_patterns,
             gitignore_patterns: Vec::new(),
             exclude_globs: HashSet::new(),
+            include_globs: HashSet::new(),
+            respect_gitignore: true,
             max_files: None,
             max_file_size_mb: 5,
             batch_size: 200,
             timeout_seconds: None,
+            finding_cache_path: ".code-research-cache".to_string(),
             severity_thresholds,
         }
     }
@@ -136,6 +159,130 @@ impl AnalyzerConfig {
             false
         }
     }
+
+    /// Whether `path` passes the `max_file_size_mb` cap and the binary
+    /// sniff, recording a skip reason on `report` when it doesn't. Collected
+    /// separately from `is_code_file`/`should_skip_path` since those decide
+    /// from the path alone, while this one has to read the file.
+    fn accept_file(&self, path: &Path, report: &mut CollectionReport) -> bool {
+        if exceeds_max_size(path, self.max_file_size_mb) {
+            report.skipped_too_large += 1;
+            return false;
+        }
+        if looks_binary(path) {
+            report.skipped_binary += 1;
+            return false;
+        }
+        true
+    }
+
+    pub fn matches_include_globs(&self, path: &Path) -> bool {
+        if self.include_globs.is_empty() {
+            return true;
+        }
+
+        let path_str = path.to_string_lossy();
+        self.include_globs.iter().any(|glob| {
+            glob::Pattern::new(glob)
+                .map(|p| p.matches(&path_str))
+                .unwrap_or(false)
+        })
+    }
+
+    /// The narrowest directory under `target_path` guaranteed to contain
+    /// every match for `include_globs`, so `collect_files` doesn't walk
+    /// subtrees no glob could possibly match. Falls back to `target_path`
+    /// when `include_globs` is empty or its patterns share no common prefix.
+    fn narrowest_root(&self, target_path: &Path) -> PathBuf {
+        if self.include_globs.is_empty() {
+            return target_path.to_path_buf();
+        }
+
+        let mut common: Option<PathBuf> = None;
+        for glob in &self.include_globs {
+            let prefix = literal_prefix_dir(glob);
+            common = Some(match common {
+                Some(existing) => common_ancestor(&existing, &prefix),
+                None => prefix,
+            });
+        }
+
+        match common {
+            Some(prefix) if prefix.as_os_str().len() > 0 => target_path.join(prefix),
+            _ => target_path.to_path_buf(),
+        }
+    }
+}
+
+/// The directory portion of `glob` before its first wildcard component,
+/// e.g. `src/**/*.rs` -> `src`, `*.rs` -> `` (empty, meaning "no narrowing").
+fn literal_prefix_dir(glob: &str) -> PathBuf {
+    let mut prefix = PathBuf::new();
+    for component in glob.split('/') {
+        if component.contains('*') || component.contains('?') || component.contains('[') {
+            break;
+        }
+        prefix.push(component);
+    }
+    prefix
+}
+
+/// The deepest shared ancestor of two relative paths.
+fn common_ancestor(a: &Path, b: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for (ca, cb) in a.components().zip(b.components()) {
+        if ca == cb {
+            result.push(ca);
+        } else {
+            break;
+        }
+    }
+    result
+}
+
+/// Counts of candidate files `collect_files` excluded for a reason other
+/// than path-based filtering, so callers can surface e.g. "skipped 12 files
+/// (too large), 4 files (binary)" instead of silently omitting them.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CollectionReport {
+    pub skipped_too_large: usize,
+    pub skipped_binary: usize,
+}
+
+fn exceeds_max_size(path: &Path, max_file_size_mb: usize) -> bool {
+    std::fs::metadata(path)
+        .map(|meta| meta.len() > max_file_size_mb as u64 * 1024 * 1024)
+        .unwrap_or(false)
+}
+
+/// Cheap binary sniff: sample the first few KB and reject files containing
+/// a NUL byte or a high ratio of non-text bytes, so minified bundles or
+/// binary payloads masquerading under a code extension get excluded.
+fn looks_binary(path: &Path) -> bool {
+    const SAMPLE_SIZE: usize = 8192;
+
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return false;
+    };
+    let mut buf = vec![0u8; SAMPLE_SIZE];
+    let Ok(read) = file.read(&mut buf) else {
+        return false;
+    };
+    let sample = &buf[..read];
+    if sample.is_empty() {
+        return false;
+    }
+
+    if sample.contains(&0) {
+        return true;
+    }
+
+    let non_text = sample
+        .iter()
+        .filter(|&&b| !(b == b'\n' || b == b'\r' || b == b'\t' || (0x20..0x7f).contains(&b)))
+        .count();
+
+    non_text as f64 / sample.len() as f64 > 0.3
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -176,6 +323,13 @@ pub trait Analyzer: Send + Sync {
 
     /// Get analyzer description
     fn description(&self) -> &str;
+
+    /// External binaries this analyzer shells out to, if any. Used by the
+    /// CLI's `--confirm-tools` gate, which asks the constructed analyzer for
+    /// them to explain what will run before `analyze` is called.
+    fn external_commands(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 /// Create an analyzer configuration with common defaults
@@ -193,34 +347,266 @@ pub fn create_analyzer_config(
     config
 }
 
-/// Collect files for analysis
-pub fn collect_files(config: &AnalyzerConfig) -> Result<Vec<PathBuf>> {
+/// Collect files for analysis, alongside a report of candidates skipped for
+/// being too large or looking binary.
+///
+/// Directories matching `skip_patterns`/`gitignore_patterns`/`exclude_globs`
+/// are pruned from the walk itself rather than fully descended into and
+/// filtered out file-by-file, and the walk starts from the narrowest root
+/// `include_globs` could possibly match — both cut I/O on large repos
+/// without changing the resulting file set. When `respect_gitignore` is set
+/// (the default), traversal defers to `ignore::WalkBuilder` for real,
+/// nested-`.gitignore`-aware pruning instead of the flat `glob::Pattern`
+/// matching `should_skip_path` does.
+pub fn collect_files(config: &AnalyzerConfig) -> Result<(Vec<PathBuf>, CollectionReport)> {
     let target_path = Path::new(&config.target_path);
     let mut files = Vec::new();
+    let mut report = CollectionReport::default();
 
     if target_path.is_file() {
-        if config.is_code_file(target_path) && !config.should_skip_path(target_path) {
+        if config.is_code_file(target_path)
+            && !config.should_skip_path(target_path)
+            && config.accept_file(target_path, &mut report)
+        {
             files.push(target_path.to_path_buf());
         }
-    } else if target_path.is_dir() {
-        for entry in WalkDir::new(target_path) {
-            let entry = entry?;
-            let path = entry.path();
+        return Ok((files, report));
+    }
 
-            if path.is_file()
-                && config.is_code_file(path)
-                && !config.should_skip_path(path)
-            {
-                files.push(path.to_path_buf());
+    if !target_path.is_dir() {
+        return Ok((files, report));
+    }
+
+    if config.respect_gitignore {
+        return collect_files_with_ignore(config, target_path);
+    }
+
+    let walk_root = config.narrowest_root(target_path);
+    let walker = WalkDir::new(&walk_root)
+        .into_iter()
+        .filter_entry(|entry| !entry.file_type().is_dir() || !config.should_skip_path(entry.path()));
+
+    for entry in walker {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_file()
+            && config.is_code_file(path)
+            && config.matches_include_globs(path)
+            && !config.should_skip_path(path)
+            && config.accept_file(path, &mut report)
+        {
+            files.push(path.to_path_buf());
+
+            if let Some(max) = config.max_files {
+                if files.len() >= max {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok((files, report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_prefix_dir_stops_at_first_wildcard_component() {
+        assert_eq!(literal_prefix_dir("src/**/*.rs"), PathBuf::from("src"));
+        assert_eq!(literal_prefix_dir("src/lib/*.rs"), PathBuf::from("src/lib"));
+        assert_eq!(literal_prefix_dir("*.rs"), PathBuf::from(""));
+        assert_eq!(literal_prefix_dir("src/lib?/*.rs"), PathBuf::from("src"));
+        assert_eq!(literal_prefix_dir("src/[ab]c/*.rs"), PathBuf::from("src"));
+    }
+
+    #[test]
+    fn common_ancestor_stops_at_first_divergent_component() {
+        assert_eq!(
+            common_ancestor(Path::new("src/a/b"), Path::new("src/a/c")),
+            PathBuf::from("src/a"),
+        );
+        assert_eq!(
+            common_ancestor(Path::new("src/a"), Path::new("lib/a")),
+            PathBuf::from(""),
+        );
+        assert_eq!(
+            common_ancestor(Path::new("src/a/b"), Path::new("src/a/b")),
+            PathBuf::from("src/a/b"),
+        );
+    }
+
+    fn config_with_include_globs(globs: &[&str]) -> AnalyzerConfig {
+        let mut config = AnalyzerConfig::default();
+        config.include_globs = globs.iter().map(|g| g.to_string()).collect();
+        config
+    }
+
+    #[test]
+    fn narrowest_root_is_target_path_when_include_globs_is_empty() {
+        let config = AnalyzerConfig::default();
+        assert_eq!(config.narrowest_root(Path::new("/repo")), PathBuf::from("/repo"));
+    }
+
+    #[test]
+    fn narrowest_root_narrows_to_a_single_globs_literal_prefix() {
+        let config = config_with_include_globs(&["src/**/*.rs"]);
+        assert_eq!(config.narrowest_root(Path::new("/repo")), PathBuf::from("/repo/src"));
+    }
+
+    #[test]
+    fn narrowest_root_narrows_to_shared_prefix_of_multiple_globs() {
+        let config = config_with_include_globs(&["src/a/**/*.rs", "src/b/*.rs"]);
+        assert_eq!(config.narrowest_root(Path::new("/repo")), PathBuf::from("/repo/src"));
+    }
+
+    #[test]
+    fn narrowest_root_falls_back_to_target_path_when_globs_share_no_prefix() {
+        let config = config_with_include_globs(&["src/*.rs", "lib/*.rs"]);
+        assert_eq!(config.narrowest_root(Path::new("/repo")), PathBuf::from("/repo"));
+    }
+
+    #[test]
+    fn collect_files_with_ignore_prunes_gitignored_directories() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "ignored/\n").unwrap();
+        std::fs::create_dir(dir.path().join("ignored")).unwrap();
+        std::fs::write(dir.path().join("ignored/skipped.rs"), "fn skipped() {}\n").unwrap();
+        std::fs::write(dir.path().join("kept.rs"), "fn kept() {}\n").unwrap();
+
+        let mut config = AnalyzerConfig::default();
+        config.target_path = dir.path().to_string_lossy().to_string();
+
+        let (files, _report) = collect_files_with_ignore(&config, dir.path()).unwrap();
+        let names: HashSet<String> = files
+            .iter()
+            .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .collect();
+
+        assert!(names.contains("kept.rs"));
+        assert!(!names.contains("skipped.rs"));
+    }
+
+    #[test]
+    fn collect_files_with_ignore_applies_negation_rules() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "*.rs\n!kept.rs\n").unwrap();
+        std::fs::write(dir.path().join("kept.rs"), "fn kept() {}\n").unwrap();
+        std::fs::write(dir.path().join("skipped.rs"), "fn skipped() {}\n").unwrap();
+
+        let mut config = AnalyzerConfig::default();
+        config.target_path = dir.path().to_string_lossy().to_string();
+
+        let (files, _report) = collect_files_with_ignore(&config, dir.path()).unwrap();
+        let names: HashSet<String> = files
+            .iter()
+            .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .collect();
+
+        assert!(names.contains("kept.rs"));
+        assert!(!names.contains("skipped.rs"));
+    }
+
+    #[test]
+    fn exceeds_max_size_respects_the_configured_cap() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("file.txt");
+        std::fs::write(&path, vec![0u8; 2 * 1024 * 1024]).unwrap();
+
+        assert!(!exceeds_max_size(&path, 5));
+        assert!(exceeds_max_size(&path, 1));
+    }
+
+    #[test]
+    fn exceeds_max_size_is_false_for_a_missing_file() {
+        assert!(!exceeds_max_size(Path::new("/does/not/exist"), 5));
+    }
+
+    #[test]
+    fn looks_binary_is_false_for_plain_text() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("file.txt");
+        std::fs::write(&path, "fn main() {\n    println!(\"hi\");\n}\n").unwrap();
+
+        assert!(!looks_binary(&path));
+    }
+
+    #[test]
+    fn looks_binary_is_true_for_a_nul_byte() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("file.bin");
+        std::fs::write(&path, [b'a', b'b', 0u8, b'c']).unwrap();
+
+        assert!(looks_binary(&path));
+    }
+
+    #[test]
+    fn looks_binary_is_true_for_a_high_ratio_of_non_text_bytes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("file.bin");
+        std::fs::write(&path, vec![0xffu8; 64]).unwrap();
+
+        assert!(looks_binary(&path));
+    }
+
+    #[test]
+    fn looks_binary_is_false_for_an_empty_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("empty.txt");
+        std::fs::write(&path, []).unwrap();
+
+        assert!(!looks_binary(&path));
+    }
+}
+
+/// Walk `target_path` with `ignore::WalkBuilder`, which honors nested
+/// `.gitignore`/`.ignore` files, negation rules, and directory-anchored
+/// patterns with the same precedence `git` itself uses. `skip_patterns` and
+/// `exclude_globs` are fed in as additional negated overrides so they still
+/// prune matching directories without a full descent, on top of whatever
+/// the real gitignore rules already exclude.
+fn collect_files_with_ignore(
+    config: &AnalyzerConfig,
+    target_path: &Path,
+) -> Result<(Vec<PathBuf>, CollectionReport)> {
+    let walk_root = config.narrowest_root(target_path);
+
+    let mut overrides = OverrideBuilder::new(&walk_root);
+    for pattern in &config.skip_patterns {
+        overrides.add(&format!("!{}", pattern))?;
+        overrides.add(&format!("!**/{}/**", pattern))?;
+    }
+    for glob in &config.exclude_globs {
+        overrides.add(&format!("!{}", glob))?;
+    }
+
+    let mut builder = WalkBuilder::new(&walk_root);
+    // Apply `.gitignore` rules even when `target_path` isn't inside a git checkout.
+    builder.require_git(false);
+    builder.overrides(overrides.build()?);
+
+    let mut files = Vec::new();
+    let mut report = CollectionReport::default();
+    for entry in builder.build() {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_file()
+            && config.is_code_file(path)
+            && config.matches_include_globs(path)
+            && config.accept_file(path, &mut report)
+        {
+            files.push(path.to_path_buf());
 
-                if let Some(max) = config.max_files {
-                    if files.len() >= max {
-                        break;
-                    }
+            if let Some(max) = config.max_files {
+                if files.len() >= max {
+                    break;
                 }
             }
         }
     }
 
-    Ok(files)
+    Ok((files, report))
 }
\ No newline at end of file
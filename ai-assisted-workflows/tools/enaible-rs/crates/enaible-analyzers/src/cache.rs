@@ -0,0 +1,181 @@
+use crate::base::{AnalysisResult, AnalyzerConfig};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Metadata recorded alongside each cached blob so a later run can confirm a
+/// hit without re-reading the blob itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub input_digest: String,
+    pub cached_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct CacheIndex {
+    #[serde(default)]
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+/// Content-addressed result cache for `analyzers run`, keyed on the analyzer
+/// identity, its resolved config, and the content hash of every target file.
+/// Blobs live at `<artifacts_root>/cache/<key>.json`, alongside an index
+/// mapping key to the input digest it was produced from.
+pub struct ResultCache {
+    cache_dir: PathBuf,
+    index_path: PathBuf,
+}
+
+impl ResultCache {
+    pub fn new(artifacts_root: &Path) -> Self {
+        let cache_dir = artifacts_root.join("cache");
+        Self {
+            index_path: cache_dir.join("index.json"),
+            cache_dir,
+        }
+    }
+
+    /// Compute a stable `(cache_key, input_digest)` pair from the analyzer
+    /// id, its version, the resolved config, and the sorted list of target
+    /// files each paired with its content hash. Hashing is deterministic
+    /// across runs: inputs are sorted and SHA-256 is fixed.
+    pub fn compute_key(
+        analyzer_id: &str,
+        tool_version: &str,
+        config: &AnalyzerConfig,
+        files: &[PathBuf],
+    ) -> Result<(String, String)> {
+        let config_json = serde_json::to_string(config)?;
+
+        let mut sorted_files = files.to_vec();
+        sorted_files.sort();
+
+        let mut input_hasher = Sha256::new();
+        for file in &sorted_files {
+            let contents = fs::read(file)
+                .with_context(|| format!("Failed to read {} for cache key", file.display()))?;
+            let mut file_hasher = Sha256::new();
+            file_hasher.update(&contents);
+
+            input_hasher.update(file.to_string_lossy().as_bytes());
+            input_hasher.update(to_hex(&file_hasher.finalize()).as_bytes());
+        }
+        let input_digest = to_hex(&input_hasher.finalize());
+
+        let mut key_hasher = Sha256::new();
+        key_hasher.update(analyzer_id.as_bytes());
+        key_hasher.update(tool_version.as_bytes());
+        key_hasher.update(config_json.as_bytes());
+        key_hasher.update(input_digest.as_bytes());
+        let key = to_hex(&key_hasher.finalize());
+
+        Ok((key, input_digest))
+    }
+
+    fn blob_path(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(format!("{}.json", key))
+    }
+
+    fn load_index(&self) -> CacheIndex {
+        fs::read_to_string(&self.index_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_index(&self, index: &CacheIndex) -> Result<()> {
+        fs::create_dir_all(&self.cache_dir)?;
+        let json = serde_json::to_string_pretty(index)?;
+        atomic_write(&self.index_path, json.as_bytes())
+    }
+
+    /// Look up a cached result, refusing to serve it if the recorded input
+    /// digest no longer matches `input_digest`.
+    pub fn get(&self, key: &str, input_digest: &str) -> Option<AnalysisResult> {
+        let index = self.load_index();
+        let entry = index.entries.get(key)?;
+        if entry.input_digest != input_digest {
+            return None;
+        }
+
+        let contents = fs::read_to_string(self.blob_path(key)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Write a result blob atomically (temp file + rename) and record it in
+    /// the index, so a crash mid-write never leaves a torn cache entry.
+    pub fn put(&self, key: &str, input_digest: &str, result: &AnalysisResult) -> Result<()> {
+        fs::create_dir_all(&self.cache_dir)?;
+
+        let json = serde_json::to_string_pretty(result)?;
+        atomic_write(&self.blob_path(key), json.as_bytes())?;
+
+        let mut index = self.load_index();
+        index.entries.insert(
+            key.to_string(),
+            CacheEntry {
+                input_digest: input_digest.to_string(),
+                cached_at: Utc::now().to_rfc3339(),
+            },
+        );
+        self.save_index(&index)
+    }
+
+    /// Remove every cached blob and the index (`analyzers cache clear`).
+    pub fn clear(&self) -> Result<()> {
+        if self.cache_dir.exists() {
+            fs::remove_dir_all(&self.cache_dir)?;
+        }
+        Ok(())
+    }
+
+    /// Drop index entries with no backing blob and orphaned blobs with no
+    /// index entry (`analyzers cache prune`). Returns the number removed.
+    pub fn prune(&self) -> Result<usize> {
+        let mut index = self.load_index();
+        let mut removed = 0;
+
+        let stale_keys: Vec<String> = index
+            .entries
+            .keys()
+            .filter(|key| !self.blob_path(key).exists())
+            .cloned()
+            .collect();
+        for key in &stale_keys {
+            index.entries.remove(key);
+            removed += 1;
+        }
+
+        if self.cache_dir.exists() {
+            for entry in fs::read_dir(&self.cache_dir)? {
+                let path = entry?.path();
+                if path == self.index_path {
+                    continue;
+                }
+                let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+                if !index.entries.contains_key(stem) {
+                    fs::remove_file(&path)?;
+                    removed += 1;
+                }
+            }
+        }
+
+        self.save_index(&index)?;
+        Ok(removed)
+    }
+}
+
+fn atomic_write(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
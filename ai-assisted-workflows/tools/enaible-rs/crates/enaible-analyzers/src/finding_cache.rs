@@ -0,0 +1,141 @@
+use crate::base::{AnalyzerConfig, Finding};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FindingCacheEntry {
+    content_hash: String,
+    config_digest: String,
+    findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct FindingCacheIndex {
+    #[serde(default)]
+    entries: HashMap<String, FindingCacheEntry>,
+}
+
+/// Content-hash cache of per-file findings, keyed on file path, so repeated
+/// runs over a mostly-unchanged tree skip re-analyzing files `run_analysis`
+/// has already seen. Unlike `ResultCache` (one blob per whole-analysis-run
+/// key), this caches at file granularity so a single changed file doesn't
+/// invalidate every other file's findings. Persisted next to the target at
+/// `AnalyzerConfig::finding_cache_path` as a single serde-serialized index.
+///
+/// The index is read from disk once (in `for_config`/`new`) and held in
+/// memory behind a `Mutex` for the lifetime of the cache; `get`/`put` only
+/// touch that in-memory map (plus a read of the looked-up file to hash its
+/// content), so `run_analysis`'s per-file worker loop never reads or writes
+/// the index file per file. Call `flush` once after all workers finish to
+/// write the accumulated index back out.
+pub struct FindingCache {
+    index_path: PathBuf,
+    index: Mutex<FindingCacheIndex>,
+}
+
+impl FindingCache {
+    pub fn new(cache_root: &Path) -> Self {
+        let index_path = cache_root.join("findings.json");
+        let index = Mutex::new(Self::load_index(&index_path));
+        Self { index_path, index }
+    }
+
+    /// Resolve the cache from `config.finding_cache_path`, relative to
+    /// `target_path` (or its parent directory, when `target_path` names a
+    /// single file).
+    pub fn for_config(config: &AnalyzerConfig) -> Self {
+        let target_path = Path::new(&config.target_path);
+        let base = if target_path.is_dir() {
+            target_path.to_path_buf()
+        } else {
+            target_path.parent().map(Path::to_path_buf).unwrap_or_default()
+        };
+        Self::new(&base.join(&config.finding_cache_path))
+    }
+
+    /// A digest of everything that affects whether a cached finding is
+    /// still valid besides the file's own content: the analyzer's identity,
+    /// version, and resolved config (including severity thresholds), so a
+    /// rule change invalidates every cached entry rather than serving stale
+    /// findings under new rules.
+    pub fn config_digest(analyzer_id: &str, analyzer_version: &str, config: &AnalyzerConfig) -> Result<String> {
+        let config_json = serde_json::to_string(config)?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(analyzer_id.as_bytes());
+        hasher.update(analyzer_version.as_bytes());
+        hasher.update(config_json.as_bytes());
+        Ok(to_hex(&hasher.finalize()))
+    }
+
+    fn content_hash(path: &Path) -> Result<String> {
+        let bytes = fs::read(path)
+            .with_context(|| format!("Failed to read {} for finding cache", path.display()))?;
+        let mut hasher = Sha256::new();
+        hasher.update(&bytes);
+        Ok(to_hex(&hasher.finalize()))
+    }
+
+    fn load_index(index_path: &Path) -> FindingCacheIndex {
+        fs::read_to_string(index_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the in-memory index out to `index_path`. Called once after all
+    /// of `run_analysis`'s workers finish, not per file.
+    pub fn flush(&self) -> Result<()> {
+        if let Some(parent) = self.index_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&*self.index.lock().unwrap())?;
+        atomic_write(&self.index_path, json.as_bytes())
+    }
+
+    /// Look up `path`'s cached findings, refusing to serve them if the file
+    /// content has changed since they were cached or `config_digest` no
+    /// longer matches (an analyzer/config change).
+    pub fn get(&self, path: &Path, config_digest: &str) -> Option<Vec<Finding>> {
+        let index = self.index.lock().unwrap();
+        let entry = index.entries.get(&path.to_string_lossy().to_string())?;
+        if entry.config_digest != config_digest {
+            return None;
+        }
+        if entry.content_hash != Self::content_hash(path).ok()? {
+            return None;
+        }
+        Some(entry.findings.clone())
+    }
+
+    /// Record `findings` for `path` under its current content hash, in
+    /// memory only — call `flush` to persist it to disk.
+    pub fn put(&self, path: &Path, config_digest: &str, findings: &[Finding]) -> Result<()> {
+        let content_hash = Self::content_hash(path)?;
+        self.index.lock().unwrap().entries.insert(
+            path.to_string_lossy().to_string(),
+            FindingCacheEntry {
+                content_hash,
+                config_digest: config_digest.to_string(),
+                findings: findings.to_vec(),
+            },
+        );
+        Ok(())
+    }
+}
+
+fn atomic_write(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
@@ -1,7 +1,20 @@
 pub mod base;
+pub mod cache;
+pub mod finding_cache;
+pub mod plugin;
 pub mod registry;
+pub mod runner;
+pub mod supply_chain;
+pub mod watch;
 
 pub use base::{
-    AnalysisResult, Analyzer, AnalyzerConfig, Finding, collect_files, create_analyzer_config,
+    AnalysisResult, Analyzer, AnalyzerConfig, CollectionReport, Finding, collect_files,
+    create_analyzer_config,
 };
-pub use registry::{AnalyzerRegistry, bootstrap_registry};
\ No newline at end of file
+pub use cache::ResultCache;
+pub use finding_cache::FindingCache;
+pub use plugin::PluginAnalyzer;
+pub use registry::{AnalyzerRegistry, bootstrap_registry};
+pub use runner::run_analysis;
+pub use supply_chain::SupplyChainAnalyzer;
+pub use watch::watch;
\ No newline at end of file
@@ -0,0 +1,182 @@
+use crate::base::{AnalysisResult, Analyzer, AnalyzerConfig};
+use crate::registry::AnalyzerRegistry;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Directory, relative to the repo root, scanned for external analyzer
+/// plugins (executables speaking the stdio JSON-RPC protocol below).
+pub const PLUGIN_DIR: &str = "plugins";
+
+#[derive(Debug, Serialize)]
+struct DescribeRequest {
+    describe: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct DescribeResponse {
+    id: String,
+    description: String,
+}
+
+/// An analyzer backed by an external executable (inspired by Nushell's
+/// subprocess plugin model). `analyze` spawns a fresh process per
+/// invocation, writes the resolved `AnalyzerConfig` as JSON to its stdin,
+/// and parses an `AnalysisResult` back from its stdout.
+pub struct PluginAnalyzer {
+    path: PathBuf,
+    id: String,
+    description: String,
+    config: AnalyzerConfig,
+    external_commands: Vec<String>,
+}
+
+impl PluginAnalyzer {
+    fn new(path: PathBuf, id: String, description: String, config: &AnalyzerConfig) -> Self {
+        let external_commands = vec![path.display().to_string()];
+        Self {
+            path,
+            id,
+            description,
+            config: config.clone(),
+            external_commands,
+        }
+    }
+}
+
+impl Analyzer for PluginAnalyzer {
+    fn analyze(&self, target: &str) -> Result<AnalysisResult> {
+        let verbose = std::env::var("ENAIBLE_VERBOSE").is_ok();
+
+        let mut config = self.config.clone();
+        config.target_path = target.to_string();
+
+        let mut child = Command::new(&self.path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(if verbose { Stdio::inherit() } else { Stdio::null() })
+            .spawn()
+            .with_context(|| format!("Failed to spawn plugin {}", self.path.display()))?;
+
+        {
+            let stdin = child
+                .stdin
+                .as_mut()
+                .ok_or_else(|| anyhow!("Plugin {} closed stdin", self.path.display()))?;
+            stdin.write_all(&serde_json::to_vec(&config)?)?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .with_context(|| format!("Failed to read plugin {} output", self.path.display()))?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Plugin {} exited with {}",
+                self.path.display(),
+                output.status
+            ));
+        }
+
+        serde_json::from_slice(&output.stdout).with_context(|| {
+            format!(
+                "Plugin {} returned invalid AnalysisResult JSON",
+                self.path.display()
+            )
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn external_commands(&self) -> Vec<String> {
+        self.external_commands.clone()
+    }
+}
+
+fn describe_plugin(path: &Path) -> Result<DescribeResponse> {
+    let mut child = Command::new(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to spawn plugin {}", path.display()))?;
+
+    {
+        let stdin = child
+            .stdin
+            .as_mut()
+            .ok_or_else(|| anyhow!("Plugin {} closed stdin", path.display()))?;
+        stdin.write_all(&serde_json::to_vec(&DescribeRequest { describe: true })?)?;
+    }
+
+    let output = child.wait_with_output().with_context(|| {
+        format!("Failed to read plugin {} describe response", path.display())
+    })?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Plugin {} describe handshake failed with {}",
+            path.display(),
+            output.status
+        );
+    }
+
+    serde_json::from_slice(&output.stdout).with_context(|| {
+        format!("Plugin {} returned invalid describe response", path.display())
+    })
+}
+
+/// Scan `dir` for executable plugin files and register each with `registry`
+/// under the id its `{"describe": true}` handshake reports. Plugins that
+/// fail to spawn or answer the handshake are silently skipped.
+pub fn discover_plugins(dir: &Path, registry: &AnalyzerRegistry) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() || !is_executable(&path) {
+            continue;
+        }
+
+        let Ok(described) = describe_plugin(&path) else {
+            continue;
+        };
+
+        let plugin_path = path.clone();
+        let id = described.id.clone();
+        let description = described.description.clone();
+        registry
+            .register(&described.id, move |config| {
+                Box::new(PluginAnalyzer::new(
+                    plugin_path.clone(),
+                    id.clone(),
+                    description.clone(),
+                    config,
+                ))
+            })
+            .ok();
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|meta| meta.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.extension().map(|ext| ext == "exe").unwrap_or(false)
+}
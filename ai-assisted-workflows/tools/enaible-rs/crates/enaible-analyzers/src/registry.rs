@@ -2,6 +2,7 @@ use crate::base::{Analyzer, AnalyzerConfig};
 use anyhow::{anyhow, Result};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 type AnalyzerFactory = Box<dyn Fn(&AnalyzerConfig) -> Box<dyn Analyzer> + Send + Sync>;
@@ -122,8 +123,10 @@ impl Analyzer for QualityAnalyzer {
     }
 }
 
-/// Bootstrap the registry with default analyzers
-pub fn bootstrap_registry() {
+/// Bootstrap the registry with default analyzers, then scan `repo_root`'s
+/// `plugins/` directory for external analyzer executables and register each
+/// one under the id its describe handshake reports (see `crate::plugin`).
+pub fn bootstrap_registry(repo_root: &Path) {
     let registry = AnalyzerRegistry::global();
 
     // Register stub analyzers
@@ -138,4 +141,12 @@ pub fn bootstrap_registry() {
             Box::new(QualityAnalyzer::new(config))
         })
         .ok();
+
+    registry
+        .register("security:supply-chain", |config| {
+            Box::new(crate::supply_chain::SupplyChainAnalyzer::new(config))
+        })
+        .ok();
+
+    crate::plugin::discover_plugins(&repo_root.join(crate::plugin::PLUGIN_DIR), registry);
 }
\ No newline at end of file
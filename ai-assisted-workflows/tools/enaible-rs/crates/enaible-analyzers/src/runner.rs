@@ -0,0 +1,187 @@
+use crate::base::{collect_files, AnalysisResult, Analyzer, AnalyzerConfig, CollectionReport, Finding};
+use crate::finding_cache::FindingCache;
+use anyhow::Result;
+use crossbeam::channel;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Run `analyzer` over every file `collect_files` selects, split into
+/// `config.batch_size`-sized chunks dispatched across a worker pool sized
+/// from the available cores, instead of the implicit single-threaded
+/// `Analyzer::analyze` call on the whole target. Each file is first looked
+/// up in the `FindingCache` keyed on its content hash plus the analyzer's
+/// identity, version, and config (so a rule change invalidates stale
+/// entries); a hit reuses the cached findings, a miss analyzes and records
+/// them in the cache's in-memory index, which is flushed to disk once after
+/// every worker finishes rather than on each file. Each worker streams its
+/// chunk's findings back to this
+/// function over a crossbeam channel, which merges them into one
+/// `AnalysisResult` and rebuilds the `summary` map by summing every chunk's
+/// own summary counters. If `config.timeout_seconds` elapses before all
+/// files are processed, outstanding work is cancelled (a shared stop flag
+/// checked between files) and `metadata["partial"]` is set so callers know
+/// the result is incomplete.
+pub fn run_analysis(analyzer: &dyn Analyzer, config: &AnalyzerConfig) -> Result<AnalysisResult> {
+    let (files, collection_report) = collect_files(config)?;
+    let total = files.len();
+
+    if total == 0 {
+        let mut result = AnalysisResult::default();
+        insert_collection_metadata(&mut result.metadata, &collection_report);
+        return Ok(result);
+    }
+
+    let cache = Arc::new(FindingCache::for_config(config));
+    let config_digest = FindingCache::config_digest(analyzer.name(), env!("CARGO_PKG_VERSION"), config)?;
+
+    let chunks: Vec<Vec<PathBuf>> = files
+        .chunks(config.batch_size.max(1))
+        .map(|chunk| chunk.to_vec())
+        .collect();
+
+    let worker_count = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(chunks.len());
+
+    let (sender, receiver) = channel::unbounded::<(Vec<Finding>, HashMap<String, usize>)>();
+    let queue = Arc::new(Mutex::new(chunks.into_iter()));
+    let processed = Arc::new(AtomicUsize::new(0));
+    let cache_hits = Arc::new(AtomicUsize::new(0));
+    let cache_misses = Arc::new(AtomicUsize::new(0));
+    let stop = Arc::new(AtomicBool::new(false));
+    let timed_out = Arc::new(AtomicBool::new(false));
+
+    if let Some(timeout) = config.timeout_seconds {
+        let stop = Arc::clone(&stop);
+        let timed_out = Arc::clone(&timed_out);
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(timeout));
+            timed_out.store(true, Ordering::SeqCst);
+            stop.store(true, Ordering::SeqCst);
+        });
+    }
+
+    std::thread::scope(|scope| {
+        for _ in 0..worker_count {
+            let queue = Arc::clone(&queue);
+            let sender = sender.clone();
+            let processed = Arc::clone(&processed);
+            let cache_hits = Arc::clone(&cache_hits);
+            let cache_misses = Arc::clone(&cache_misses);
+            let cache = Arc::clone(&cache);
+            let config_digest = config_digest.clone();
+            let stop = Arc::clone(&stop);
+
+            scope.spawn(move || loop {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                let chunk = match queue.lock().unwrap().next() {
+                    Some(chunk) => chunk,
+                    None => break,
+                };
+
+                let mut chunk_findings = Vec::new();
+                let mut chunk_summary: HashMap<String, usize> = HashMap::new();
+
+                for file in &chunk {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    let cached = cache.get(file, &config_digest);
+                    if let Some(findings) = cached {
+                        cache_hits.fetch_add(1, Ordering::SeqCst);
+                        chunk_findings.extend(findings);
+                    } else if let Ok(result) = analyzer.analyze(&file.to_string_lossy()) {
+                        cache_misses.fetch_add(1, Ordering::SeqCst);
+                        cache.put(file, &config_digest, &result.findings).ok();
+                        chunk_findings.extend(result.findings);
+                        for (key, value) in result.summary {
+                            *chunk_summary.entry(key).or_insert(0) += value;
+                        }
+                    }
+                    processed.fetch_add(1, Ordering::SeqCst);
+                }
+
+                sender.send((chunk_findings, chunk_summary)).ok();
+            });
+        }
+        drop(sender);
+
+        report_progress(&processed, total, &stop);
+    });
+
+    cache.flush()?;
+
+    let mut findings = Vec::new();
+    let mut summary: HashMap<String, usize> = HashMap::new();
+    for (batch_findings, batch_summary) in receiver {
+        findings.extend(batch_findings);
+        for (key, value) in batch_summary {
+            *summary.entry(key).or_insert(0) += value;
+        }
+    }
+
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        "files_analyzed".to_string(),
+        processed.load(Ordering::SeqCst).to_string(),
+    );
+    metadata.insert("files_total".to_string(), total.to_string());
+    metadata.insert(
+        "cache_hits".to_string(),
+        cache_hits.load(Ordering::SeqCst).to_string(),
+    );
+    metadata.insert(
+        "cache_misses".to_string(),
+        cache_misses.load(Ordering::SeqCst).to_string(),
+    );
+    if timed_out.load(Ordering::SeqCst) {
+        metadata.insert("partial".to_string(), "true".to_string());
+    }
+    insert_collection_metadata(&mut metadata, &collection_report);
+
+    Ok(AnalysisResult {
+        findings,
+        summary,
+        metadata,
+    })
+}
+
+/// Record `collect_files`' skip counts on `metadata` so callers can surface
+/// e.g. "skipped 12 files (too large), 4 files (binary)" instead of it going
+/// unnoticed.
+fn insert_collection_metadata(metadata: &mut HashMap<String, String>, report: &CollectionReport) {
+    metadata.insert(
+        "skipped_too_large".to_string(),
+        report.skipped_too_large.to_string(),
+    );
+    metadata.insert(
+        "skipped_binary".to_string(),
+        report.skipped_binary.to_string(),
+    );
+}
+
+/// Poll `processed` until every file is accounted for (or `stop` fires),
+/// printing a live "N/total files" line to stderr the way a build tool
+/// would report progress on a long-running multicore pipeline.
+fn report_progress(processed: &AtomicUsize, total: usize, stop: &AtomicBool) {
+    loop {
+        let done = processed.load(Ordering::SeqCst);
+        eprint!("\rAnalyzing: {}/{} files", done, total);
+        std::io::stderr().flush().ok();
+
+        if done >= total || stop.load(Ordering::SeqCst) {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    eprintln!();
+}
@@ -0,0 +1,124 @@
+use crate::base::{collect_files, AnalysisResult, Analyzer, AnalyzerConfig, Finding};
+use anyhow::Result;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long to keep collecting filesystem events after the first one before
+/// acting, so a burst of saves from one edit collapses into a single cycle.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Long-running watch mode: does an initial full scan of `config.target_path`
+/// with `analyzer`, then re-analyzes only what changed on every subsequent
+/// filesystem event (debounced so a burst of saves collapses into one
+/// cycle), calling `on_update` with a fresh `AnalysisResult` after each
+/// cycle. A changed file's whole directory is re-analyzed alongside it
+/// rather than just that one file, since findings in neighboring files can
+/// depend on it. `is_code_file`/`should_skip_path` gate incoming events the
+/// same way they gate the initial scan, so editor temp files and ignored
+/// paths don't trigger churn. Runs until the watcher's event channel closes.
+pub fn watch(
+    analyzer: &dyn Analyzer,
+    config: &AnalyzerConfig,
+    mut on_update: impl FnMut(&AnalysisResult),
+) -> Result<()> {
+    let target_path = Path::new(&config.target_path);
+    let mut index: HashMap<PathBuf, Vec<Finding>> = HashMap::new();
+
+    let (initial_files, _collection_report) = collect_files(config)?;
+    for file in initial_files {
+        analyze_into_index(analyzer, &file, &mut index);
+    }
+    on_update(&build_result(&index));
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            sender.send(event).ok();
+        }
+    })?;
+    watcher.watch(target_path, RecursiveMode::Recursive)?;
+
+    while let Ok(first) = receiver.recv() {
+        let mut events = vec![first];
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+            events.push(event);
+        }
+
+        let mut changed_dirs: HashSet<PathBuf> = HashSet::new();
+        let mut removed: Vec<PathBuf> = Vec::new();
+
+        for event in events {
+            let is_remove = matches!(event.kind, EventKind::Remove(_));
+            for path in event.paths {
+                if !config.is_code_file(&path) || config.should_skip_path(&path) {
+                    continue;
+                }
+                if is_remove {
+                    removed.push(path.clone());
+                }
+                if let Some(parent) = path.parent() {
+                    changed_dirs.insert(parent.to_path_buf());
+                }
+            }
+        }
+
+        for path in &removed {
+            index.remove(path);
+        }
+
+        for dir in &changed_dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_file() && config.is_code_file(&path) && !config.should_skip_path(&path) {
+                    analyze_into_index(analyzer, &path, &mut index);
+                }
+            }
+        }
+
+        on_update(&build_result(&index));
+    }
+
+    Ok(())
+}
+
+/// Analyze a single file and store (or, on failure, drop) its findings in
+/// the live index, keeping merges O(changed files) instead of O(all files).
+fn analyze_into_index(analyzer: &dyn Analyzer, path: &Path, index: &mut HashMap<PathBuf, Vec<Finding>>) {
+    match analyzer.analyze(&path.to_string_lossy()) {
+        Ok(result) => {
+            index.insert(path.to_path_buf(), result.findings);
+        }
+        Err(_) => {
+            index.remove(path);
+        }
+    }
+}
+
+/// Rebuild a full `AnalysisResult` from the live index: unchanged files'
+/// findings carry over untouched, changed/deleted files' entries were
+/// already invalidated and recomputed by `analyze_into_index`.
+fn build_result(index: &HashMap<PathBuf, Vec<Finding>>) -> AnalysisResult {
+    let mut findings = Vec::new();
+    for file_findings in index.values() {
+        findings.extend(file_findings.iter().cloned());
+    }
+
+    let mut summary: HashMap<String, usize> = HashMap::new();
+    for finding in &findings {
+        *summary.entry(finding.severity.clone()).or_insert(0) += 1;
+    }
+
+    let mut metadata = HashMap::new();
+    metadata.insert("files_indexed".to_string(), index.len().to_string());
+
+    AnalysisResult {
+        findings,
+        summary,
+        metadata,
+    }
+}
@@ -2,20 +2,23 @@ use anyhow::Result;
 use chrono::Utc;
 use clap::Subcommand;
 use enaible_analyzers::{
-    AnalyzerRegistry, AnalysisResult, create_analyzer_config, bootstrap_registry,
+    Analyzer, AnalyzerRegistry, AnalysisResult, ResultCache, collect_files, create_analyzer_config,
+    bootstrap_registry,
 };
 use enaible_core::load_workspace;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::io::{IsTerminal, Write as _};
 use std::path::{Path, PathBuf};
 
 #[derive(Subcommand)]
 pub enum AnalyzersCommands {
     /// Run a registered analyzer and emit normalized results
     Run {
-        /// Analyzer registry key (e.g. quality:lizard)
-        tool: String,
+        /// Analyzer registry key (e.g. quality:lizard). Falls back to the
+        /// workspace's `default_analyzers` config when omitted.
+        tool: Option<String>,
 
         /// Path to analyze
         #[arg(short, long, default_value = ".")]
@@ -52,6 +55,82 @@ pub enum AnalyzersCommands {
         /// Additional glob patterns to exclude (repeatable)
         #[arg(short = 'x', long = "exclude")]
         exclude_glob: Vec<String>,
+
+        /// Bypass the result cache entirely (no read, no write)
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Bypass the cache read but still write a fresh entry
+        #[arg(long)]
+        force: bool,
+
+        /// Run across every discovered workspace member, emitting one
+        /// combined JSON payload keyed by member name
+        #[arg(long, conflicts_with = "member")]
+        workspace: bool,
+
+        /// Scope the run to a single workspace member by name
+        #[arg(long)]
+        member: Option<String>,
+
+        /// Print the planned invocations as JSON instead of running them
+        #[arg(long)]
+        plan: bool,
+
+        /// Diff findings against a previously written baseline JSON, only
+        /// failing on findings the baseline didn't already have
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Overwrite the --baseline file with this run's findings
+        #[arg(long, requires = "baseline")]
+        write_baseline: bool,
+
+        /// Regex on the analyzer registry key; matching tools prompt for
+        /// confirmation before running. Falls back to the workspace's
+        /// `confirm_tools` config when omitted.
+        #[arg(long)]
+        confirm_tools: Option<String>,
+
+        /// Skip the confirmation prompt raised by --confirm-tools
+        #[arg(long)]
+        yes: bool,
+
+        /// Analyze files in parallel across a worker pool instead of handing
+        /// the whole target to the analyzer in one call. Enables the
+        /// content-hash finding cache, so repeated runs over a
+        /// mostly-unchanged tree skip re-analyzing unchanged files.
+        #[arg(long)]
+        parallel: bool,
+    },
+
+    /// Watch a target and re-emit findings as it changes, for IDE/CI
+    /// feedback loops that want live results instead of a one-shot run
+    Watch {
+        /// Analyzer registry key (e.g. quality:lizard)
+        tool: String,
+
+        /// Path to watch
+        #[arg(short, long, default_value = ".")]
+        target: PathBuf,
+
+        /// Minimum severity to include in findings
+        #[arg(long, default_value = "high")]
+        min_severity: String,
+
+        /// Additional glob patterns to exclude (repeatable)
+        #[arg(short = 'x', long = "exclude")]
+        exclude_glob: Vec<String>,
+
+        /// Regex on the analyzer registry key; matching tools prompt for
+        /// confirmation before watching. Falls back to the workspace's
+        /// `confirm_tools` config when omitted.
+        #[arg(long)]
+        confirm_tools: Option<String>,
+
+        /// Skip the confirmation prompt raised by --confirm-tools
+        #[arg(long)]
+        yes: bool,
     },
 
     /// List all registered analyzers
@@ -60,17 +139,69 @@ pub enum AnalyzersCommands {
         #[arg(long, default_value = "true")]
         json: bool,
     },
+
+    /// Manage the content-addressed analyzer result cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Remove every cached result
+    Clear,
+
+    /// Drop cache entries whose blob is missing or orphaned
+    Prune,
+}
+
+/// A single planned analyzer invocation, as it would run given the current
+/// target/member/cache state, without actually executing it.
+#[derive(Serialize)]
+struct Invocation {
+    analyzer: String,
+    target_kind: String,
+    member: Option<String>,
+    inputs: Vec<String>,
+    output: String,
+    cache_path: String,
+    predicted_cache_hit: bool,
+}
+
+/// Machine-readable dry-run output for `analyzers run --plan`.
+#[derive(Serialize)]
+struct Plan {
+    invocations: Vec<Invocation>,
+}
+
+type FindingMap = HashMap<String, serde_json::Value>;
+
+/// Stable identity for a finding used to diff against a baseline: path and
+/// category and message, deliberately excluding line so a finding tolerates
+/// drift from unrelated edits elsewhere in the file.
+fn finding_fingerprint(finding: &FindingMap) -> String {
+    let get = |key: &str| finding.get(key).and_then(|v| v.as_str()).unwrap_or("");
+    format!("{}\u{1}{}\u{1}{}", get("path"), get("category"), get("message"))
 }
 
 #[derive(Serialize, Deserialize)]
 struct AnalyzerRunResponse {
     tool: String,
-    findings: Vec<HashMap<String, serde_json::Value>>,
+    findings: Vec<FindingMap>,
     summary: HashMap<String, usize>,
     metadata: HashMap<String, String>,
     started_at: f64,
     finished_at: f64,
     exit_code: i32,
+    #[serde(default)]
+    cached: bool,
+    #[serde(default)]
+    new_findings: Vec<FindingMap>,
+    #[serde(default)]
+    fixed_findings: Vec<FindingMap>,
+    #[serde(default)]
+    unchanged_count: usize,
 }
 
 impl AnalyzerRunResponse {
@@ -80,6 +211,8 @@ impl AnalyzerRunResponse {
         started_at: f64,
         finished_at: f64,
         min_severity: &str,
+        cached: bool,
+        baseline: Option<&AnalyzerRunResponse>,
     ) -> Self {
         let mut findings = Vec::new();
 
@@ -111,7 +244,33 @@ impl AnalyzerRunResponse {
             }
         }
 
-        let exit_code = if findings.is_empty() { 0 } else { 1 };
+        let (new_findings, fixed_findings, unchanged_count, exit_code) = match baseline {
+            Some(baseline) => {
+                let baseline_fps: HashSet<String> =
+                    baseline.findings.iter().map(finding_fingerprint).collect();
+                let current_fps: HashSet<String> = findings.iter().map(finding_fingerprint).collect();
+
+                let new_findings: Vec<FindingMap> = findings
+                    .iter()
+                    .filter(|f| !baseline_fps.contains(&finding_fingerprint(f)))
+                    .cloned()
+                    .collect();
+                let fixed_findings: Vec<FindingMap> = baseline
+                    .findings
+                    .iter()
+                    .filter(|f| !current_fps.contains(&finding_fingerprint(f)))
+                    .cloned()
+                    .collect();
+                let unchanged_count = findings.len() - new_findings.len();
+                let exit_code = if new_findings.is_empty() { 0 } else { 1 };
+
+                (new_findings, fixed_findings, unchanged_count, exit_code)
+            }
+            None => {
+                let exit_code = if findings.is_empty() { 0 } else { 1 };
+                (Vec::new(), Vec::new(), 0, exit_code)
+            }
+        };
 
         Self {
             tool: tool.to_string(),
@@ -121,13 +280,45 @@ impl AnalyzerRunResponse {
             started_at,
             finished_at,
             exit_code,
+            cached,
+            new_findings,
+            fixed_findings,
+            unchanged_count,
         }
     }
 }
 
-pub fn handle_command(cmd: AnalyzersCommands) -> Result<()> {
-    // Bootstrap the registry
-    bootstrap_registry();
+fn load_baseline(path: &Path) -> Option<AnalyzerRunResponse> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn write_baseline(path: &Path, response: &AnalyzerRunResponse) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(response)?)?;
+    Ok(())
+}
+
+/// Resolve `analyzers run`'s optional `tool` positional, falling back to the
+/// first entry of the workspace's `default_analyzers` config when omitted,
+/// so teams can commit a project-local default instead of typing the tool
+/// on every invocation.
+fn resolve_default_tool(tool: Option<String>, default_analyzers: &[String]) -> Result<String> {
+    match tool {
+        Some(tool) => Ok(tool),
+        None => default_analyzers.first().cloned().ok_or_else(|| {
+            anyhow::anyhow!("No analyzer specified and no `default_analyzers` configured in .enaible.toml")
+        }),
+    }
+}
+
+pub fn handle_command(cmd: AnalyzersCommands, start_dir: Option<&Path>) -> Result<()> {
+    // Loading the workspace up front lets us bootstrap the registry (and
+    // discover its `plugins/` directory) before any command dispatches.
+    let context = load_workspace(start_dir)?;
+    bootstrap_registry(&context.repo_root);
 
     match cmd {
         AnalyzersCommands::Run {
@@ -141,19 +332,104 @@ pub fn handle_command(cmd: AnalyzersCommands) -> Result<()> {
             verbose,
             no_external,
             exclude_glob,
-        } => analyzers_run(
-            &tool,
-            &target,
-            json,
-            out,
-            &min_severity,
-            max_files,
-            summary,
-            verbose,
-            no_external,
+            no_cache,
+            force,
+            workspace,
+            member,
+            plan,
+            baseline,
+            write_baseline,
+            confirm_tools,
+            yes,
+            parallel,
+        } => {
+            let tool = resolve_default_tool(tool, &context.config.default_analyzers)?;
+            if plan {
+                analyzers_plan(
+                    &tool,
+                    &target,
+                    workspace,
+                    member,
+                    out,
+                    &min_severity,
+                    max_files,
+                    summary,
+                    &exclude_glob,
+                    &context,
+                )
+            } else if workspace {
+                analyzers_run_workspace(
+                    &tool,
+                    json,
+                    out,
+                    &min_severity,
+                    max_files,
+                    summary,
+                    verbose,
+                    no_external,
+                    exclude_glob,
+                    no_cache,
+                    force,
+                    confirm_tools,
+                    yes,
+                    parallel,
+                    &context,
+                )
+            } else if let Some(member_name) = member {
+                analyzers_run_member(
+                    &tool,
+                    &member_name,
+                    json,
+                    out,
+                    &min_severity,
+                    max_files,
+                    summary,
+                    verbose,
+                    no_external,
+                    exclude_glob,
+                    no_cache,
+                    force,
+                    confirm_tools,
+                    yes,
+                    parallel,
+                    &context,
+                )
+            } else {
+                analyzers_run(
+                    &tool,
+                    &target,
+                    json,
+                    out,
+                    &min_severity,
+                    max_files,
+                    summary,
+                    verbose,
+                    no_external,
+                    exclude_glob,
+                    no_cache,
+                    force,
+                    baseline,
+                    write_baseline,
+                    confirm_tools,
+                    yes,
+                    parallel,
+                    &context,
+                )
+            }
+        }
+        AnalyzersCommands::Watch {
+            tool,
+            target,
+            min_severity,
             exclude_glob,
-        ),
+            confirm_tools,
+            yes,
+        } => {
+            let confirm_tools = confirm_tools.or_else(|| context.config.confirm_tools.clone());
+            analyzers_watch(&tool, &target, &min_severity, &exclude_glob, confirm_tools.as_deref(), yes)
+        }
         AnalyzersCommands::List { json } => analyzers_list(json),
+        AnalyzersCommands::Cache { action } => analyzers_cache(action, &context),
     }
 }
 
@@ -194,24 +470,23 @@ fn collect_gitignore_patterns(search_root: &Path) -> Vec<String> {
     patterns
 }
 
-fn analyzers_run(
+#[allow(clippy::too_many_arguments)]
+fn compute_response(
     tool: &str,
     target: &Path,
-    json_output: bool,
-    out: Option<PathBuf>,
+    artifacts_root: &Path,
     min_severity: &str,
     max_files: Option<usize>,
     summary_mode: bool,
-    _verbose: bool,
-    no_external: bool,
-    exclude_glob: Vec<String>,
-) -> Result<()> {
-    let _context = load_workspace(None)?;
-
-    if no_external {
-        std::env::set_var("ENAIBLE_DISABLE_EXTERNAL", "1");
-    }
-
+    exclude_glob: &[String],
+    no_cache: bool,
+    force: bool,
+    json_output: bool,
+    baseline: Option<&AnalyzerRunResponse>,
+    confirm_tools: Option<&str>,
+    yes: bool,
+    parallel: bool,
+) -> Result<AnalyzerRunResponse> {
     let gitignore_patterns = collect_gitignore_patterns(target);
 
     let output_format = if json_output { "json" } else { "console" };
@@ -223,57 +498,465 @@ fn analyzers_run(
     );
 
     config.gitignore_patterns = gitignore_patterns;
-    config.exclude_globs.extend(exclude_glob);
+    config.exclude_globs.extend(exclude_glob.iter().cloned());
     if let Some(max) = max_files {
         config.max_files = Some(max);
     }
 
-    let registry = AnalyzerRegistry::global();
-    let analyzer = registry.create(tool, &config)?;
+    let cache = ResultCache::new(artifacts_root);
+    let cache_key = if no_cache {
+        None
+    } else {
+        collect_files(&config)
+            .ok()
+            .and_then(|(files, _report)| ResultCache::compute_key(tool, env!("CARGO_PKG_VERSION"), &config, &files).ok())
+    };
 
     let started = Utc::now().timestamp() as f64;
-    let result = analyzer.analyze(&target.to_string_lossy())?;
+
+    let mut cached = false;
+    let result = match &cache_key {
+        Some((key, digest)) if !force => match cache.get(key, digest) {
+            Some(cached_result) => {
+                cached = true;
+                cached_result
+            }
+            None => run_analyzer(tool, &config, target, confirm_tools, yes, parallel)?,
+        },
+        _ => run_analyzer(tool, &config, target, confirm_tools, yes, parallel)?,
+    };
+
     let finished = Utc::now().timestamp() as f64;
 
-    let response = AnalyzerRunResponse::from_analysis_result(
+    if !no_cache && !cached {
+        if let Some((key, digest)) = &cache_key {
+            cache.put(key, digest, &result).ok();
+        }
+    }
+
+    Ok(AnalyzerRunResponse::from_analysis_result(
         tool,
         result,
         started,
         finished,
         min_severity,
+        cached,
+        baseline,
+    ))
+}
+
+/// Prompt the user to confirm running `tool`, which shells out to
+/// `external_commands`. Declines (without prompting) when stdin isn't a
+/// TTY, since there's nobody there to answer; callers must pass `--yes`
+/// to get past that case non-interactively.
+fn confirm_external_tool(tool: &str, external_commands: &[String], yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Ok(false);
+    }
+
+    eprint!(
+        "Analyzer '{}' will run external command(s): {}. Proceed? [y/N] ",
+        tool,
+        external_commands.join(", ")
     );
+    std::io::stderr().flush().ok();
 
-    if json_output {
-        let json_str = serde_json::to_string_pretty(&response)?;
-        if let Some(out_path) = out {
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Run the `--confirm-tools` gate shared by `analyzers run` and `analyzers
+/// watch`: if `tool` matches `confirm_tools`'s regex, prompt (or consult
+/// `yes`) before letting the caller invoke the already-constructed `analyzer`.
+fn ensure_tool_confirmed(
+    tool: &str,
+    analyzer: &dyn Analyzer,
+    confirm_tools: Option<&str>,
+    yes: bool,
+) -> Result<()> {
+    let needs_confirmation = confirm_tools
+        .map(regex::Regex::new)
+        .transpose()?
+        .map(|re| re.is_match(tool))
+        .unwrap_or(false);
+
+    if needs_confirmation {
+        let external_commands = analyzer.external_commands();
+        if !confirm_external_tool(tool, &external_commands, yes)? {
+            anyhow::bail!("Aborted: '{}' requires confirmation (pass --yes to proceed)", tool);
+        }
+    }
+
+    Ok(())
+}
+
+fn run_analyzer(
+    tool: &str,
+    config: &enaible_analyzers::AnalyzerConfig,
+    target: &Path,
+    confirm_tools: Option<&str>,
+    yes: bool,
+    parallel: bool,
+) -> Result<AnalysisResult> {
+    let registry = AnalyzerRegistry::global();
+    let analyzer = registry.create(tool, config)?;
+
+    ensure_tool_confirmed(tool, analyzer.as_ref(), confirm_tools, yes)?;
+
+    if parallel {
+        enaible_analyzers::run_analysis(analyzer.as_ref(), config)
+    } else {
+        analyzer.analyze(&target.to_string_lossy())
+    }
+}
+
+fn write_response_json(json_str: &str, out: Option<&Path>) -> Result<()> {
+    match out {
+        Some(out_path) => {
             if let Some(parent) = out_path.parent() {
                 fs::create_dir_all(parent)?;
             }
             fs::write(out_path, json_str)?;
-        } else {
-            println!("{}", json_str);
-        }
-    } else if let Some(out_path) = out {
-        let json_str = serde_json::to_string_pretty(&response)?;
-        if let Some(parent) = out_path.parent() {
-            fs::create_dir_all(parent)?;
         }
-        fs::write(out_path, json_str)?;
+        None => println!("{}", json_str),
     }
+    Ok(())
+}
 
+fn emit_findings_hint(response: &AnalyzerRunResponse) {
     if response.findings.len() >= 200 {
         eprintln!(
             "Hint: If some findings look third-party or generated, rerun with \
             `--exclude <glob>` to filter those directories."
         );
     }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn analyzers_run(
+    tool: &str,
+    target: &Path,
+    json_output: bool,
+    out: Option<PathBuf>,
+    min_severity: &str,
+    max_files: Option<usize>,
+    summary_mode: bool,
+    verbose: bool,
+    no_external: bool,
+    exclude_glob: Vec<String>,
+    no_cache: bool,
+    force: bool,
+    baseline: Option<PathBuf>,
+    write_baseline_flag: bool,
+    confirm_tools: Option<String>,
+    yes: bool,
+    parallel: bool,
+    context: &enaible_core::WorkspaceContext,
+) -> Result<()> {
+    if no_external {
+        std::env::set_var("ENAIBLE_DISABLE_EXTERNAL", "1");
+    }
+    if verbose {
+        std::env::set_var("ENAIBLE_VERBOSE", "1");
+    }
+
+    let baseline_response = baseline.as_deref().and_then(load_baseline);
+    let confirm_tools = confirm_tools.or_else(|| context.config.confirm_tools.clone());
+
+    let response = compute_response(
+        tool,
+        target,
+        &context.artifacts_root,
+        min_severity,
+        max_files,
+        summary_mode,
+        &exclude_glob,
+        no_cache,
+        force,
+        json_output,
+        baseline_response.as_ref(),
+        confirm_tools.as_deref(),
+        yes,
+        parallel,
+    )?;
+
+    if write_baseline_flag {
+        if let Some(path) = &baseline {
+            write_baseline(path, &response)?;
+        }
+    }
+
+    if json_output || out.is_some() {
+        let json_str = serde_json::to_string_pretty(&response)?;
+        write_response_json(&json_str, out.as_deref())?;
+    }
+
+    emit_findings_hint(&response);
 
     std::process::exit(response.exit_code);
 }
 
-fn analyzers_list(json_output: bool) -> Result<()> {
-    let _context = load_workspace(None)?;
+#[allow(clippy::too_many_arguments)]
+fn analyzers_run_member(
+    tool: &str,
+    member_name: &str,
+    json_output: bool,
+    out: Option<PathBuf>,
+    min_severity: &str,
+    max_files: Option<usize>,
+    summary_mode: bool,
+    verbose: bool,
+    no_external: bool,
+    exclude_glob: Vec<String>,
+    no_cache: bool,
+    force: bool,
+    confirm_tools: Option<String>,
+    yes: bool,
+    parallel: bool,
+    context: &enaible_core::WorkspaceContext,
+) -> Result<()> {
+    if no_external {
+        std::env::set_var("ENAIBLE_DISABLE_EXTERNAL", "1");
+    }
+    if verbose {
+        std::env::set_var("ENAIBLE_VERBOSE", "1");
+    }
+
+    let member = context
+        .members
+        .iter()
+        .find(|m| m.name == member_name)
+        .ok_or_else(|| anyhow::anyhow!("Unknown workspace member: {}", member_name))?;
+
+    let artifacts_root = context.artifacts_root.join(&member.name);
+    let confirm_tools = confirm_tools.or_else(|| context.config.confirm_tools.clone());
+
+    let response = compute_response(
+        tool,
+        &member.path,
+        &artifacts_root,
+        min_severity,
+        max_files,
+        summary_mode,
+        &exclude_glob,
+        no_cache,
+        force,
+        json_output,
+        None,
+        confirm_tools.as_deref(),
+        yes,
+        parallel,
+    )?;
+
+    if json_output || out.is_some() {
+        let json_str = serde_json::to_string_pretty(&response)?;
+        write_response_json(&json_str, out.as_deref())?;
+    }
+
+    emit_findings_hint(&response);
+
+    std::process::exit(response.exit_code);
+}
 
+#[allow(clippy::too_many_arguments)]
+fn analyzers_run_workspace(
+    tool: &str,
+    json_output: bool,
+    out: Option<PathBuf>,
+    min_severity: &str,
+    max_files: Option<usize>,
+    summary_mode: bool,
+    verbose: bool,
+    no_external: bool,
+    exclude_glob: Vec<String>,
+    no_cache: bool,
+    force: bool,
+    confirm_tools: Option<String>,
+    yes: bool,
+    parallel: bool,
+    context: &enaible_core::WorkspaceContext,
+) -> Result<()> {
+    if no_external {
+        std::env::set_var("ENAIBLE_DISABLE_EXTERNAL", "1");
+    }
+    if verbose {
+        std::env::set_var("ENAIBLE_VERBOSE", "1");
+    }
+
+    if context.members.is_empty() {
+        anyhow::bail!("No workspace members discovered under {}", context.repo_root.display());
+    }
+
+    let confirm_tools = confirm_tools.or_else(|| context.config.confirm_tools.clone());
+    let mut combined = serde_json::Map::new();
+    let mut any_findings = false;
+
+    for member in &context.members {
+        let artifacts_root = context.artifacts_root.join(&member.name);
+        let response = compute_response(
+            tool,
+            &member.path,
+            &artifacts_root,
+            min_severity,
+            max_files,
+            summary_mode,
+            &exclude_glob,
+            no_cache,
+            force,
+            json_output,
+            None,
+            confirm_tools.as_deref(),
+            yes,
+            parallel,
+        )?;
+
+        any_findings = any_findings || response.exit_code != 0;
+        combined.insert(member.name.clone(), serde_json::to_value(&response)?);
+    }
+
+    let json_str = serde_json::to_string_pretty(&serde_json::Value::Object(combined))?;
+    write_response_json(&json_str, out.as_deref())?;
+
+    std::process::exit(if any_findings { 1 } else { 0 });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn analyzers_plan(
+    tool: &str,
+    target: &Path,
+    workspace: bool,
+    member: Option<String>,
+    out: Option<PathBuf>,
+    min_severity: &str,
+    max_files: Option<usize>,
+    summary_mode: bool,
+    exclude_glob: &[String],
+    context: &enaible_core::WorkspaceContext,
+) -> Result<()> {
+    let targets: Vec<(PathBuf, Option<String>)> = if workspace {
+        if context.members.is_empty() {
+            anyhow::bail!("No workspace members discovered under {}", context.repo_root.display());
+        }
+        context
+            .members
+            .iter()
+            .map(|m| (m.path.clone(), Some(m.name.clone())))
+            .collect()
+    } else if let Some(member_name) = member {
+        let found = context
+            .members
+            .iter()
+            .find(|m| m.name == member_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown workspace member: {}", member_name))?;
+        vec![(found.path.clone(), Some(found.name.clone()))]
+    } else {
+        vec![(target.to_path_buf(), None)]
+    };
+
+    let output = out
+        .as_ref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "stdout".to_string());
+
+    let mut invocations = Vec::new();
+    for (target_path, member_name) in targets {
+        let artifacts_root = match &member_name {
+            Some(name) => context.artifacts_root.join(name),
+            None => context.artifacts_root.clone(),
+        };
+
+        let mut config = create_analyzer_config(&target_path.to_string_lossy(), min_severity, summary_mode, "json");
+        config.gitignore_patterns = collect_gitignore_patterns(&target_path);
+        config.exclude_globs.extend(exclude_glob.iter().cloned());
+        if let Some(max) = max_files {
+            config.max_files = Some(max);
+        }
+
+        let (inputs, _collection_report) = collect_files(&config).unwrap_or_default();
+        let cache = ResultCache::new(&artifacts_root);
+        let key_digest = ResultCache::compute_key(tool, env!("CARGO_PKG_VERSION"), &config, &inputs).ok();
+
+        let predicted_cache_hit = key_digest
+            .as_ref()
+            .map(|(key, digest)| cache.get(key, digest).is_some())
+            .unwrap_or(false);
+        let cache_path = match &key_digest {
+            Some((key, _)) => artifacts_root.join("cache").join(format!("{}.json", key)),
+            None => artifacts_root.join("cache"),
+        };
+
+        invocations.push(Invocation {
+            analyzer: tool.to_string(),
+            target_kind: if member_name.is_some() {
+                "workspace-member".to_string()
+            } else {
+                "path".to_string()
+            },
+            member: member_name,
+            inputs: inputs.iter().map(|p| p.display().to_string()).collect(),
+            output: output.clone(),
+            cache_path: cache_path.display().to_string(),
+            predicted_cache_hit,
+        });
+    }
+
+    let plan = Plan { invocations };
+    println!("{}", serde_json::to_string_pretty(&plan)?);
+    Ok(())
+}
+
+/// Run `enaible_analyzers::watch`, printing one JSON `AnalysisResult` line
+/// to stdout per re-analysis cycle so an IDE or CI feedback loop can consume
+/// it as newline-delimited JSON instead of polling `analyzers run`.
+fn analyzers_watch(
+    tool: &str,
+    target: &Path,
+    min_severity: &str,
+    exclude_glob: &[String],
+    confirm_tools: Option<&str>,
+    yes: bool,
+) -> Result<()> {
+    let gitignore_patterns = collect_gitignore_patterns(target);
+
+    let mut config = create_analyzer_config(&target.to_string_lossy(), min_severity, false, "json");
+    config.gitignore_patterns = gitignore_patterns;
+    config.exclude_globs.extend(exclude_glob.iter().cloned());
+
+    let registry = AnalyzerRegistry::global();
+    let analyzer = registry.create(tool, &config)?;
+
+    ensure_tool_confirmed(tool, analyzer.as_ref(), confirm_tools, yes)?;
+
+    enaible_analyzers::watch(analyzer.as_ref(), &config, |result| {
+        if let Ok(json_str) = serde_json::to_string(result) {
+            println!("{}", json_str);
+        }
+    })
+}
+
+fn analyzers_cache(action: CacheCommands, context: &enaible_core::WorkspaceContext) -> Result<()> {
+    let cache = ResultCache::new(&context.artifacts_root);
+
+    match action {
+        CacheCommands::Clear => {
+            cache.clear()?;
+            println!("Cache cleared.");
+        }
+        CacheCommands::Prune => {
+            let removed = cache.prune()?;
+            println!("Removed {} stale cache entr{}.", removed, if removed == 1 { "y" } else { "ies" });
+        }
+    }
+
+    Ok(())
+}
+
+fn analyzers_list(json_output: bool) -> Result<()> {
     let registry = AnalyzerRegistry::global();
     let analyzers: Vec<_> = registry.list();
 
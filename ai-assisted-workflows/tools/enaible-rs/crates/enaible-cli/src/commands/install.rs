@@ -1,7 +1,7 @@
 use anyhow::Result;
 use clap::Args;
 use enaible_core::load_workspace;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Args)]
@@ -27,8 +27,8 @@ pub struct InstallArgs {
     target: Option<PathBuf>,
 }
 
-pub fn handle_command(args: InstallArgs) -> Result<()> {
-    let workspace = load_workspace(None)?;
+pub fn handle_command(args: InstallArgs, start_dir: Option<&Path>) -> Result<()> {
+    let workspace = load_workspace(start_dir)?;
 
     if args.cli {
         println!("Installing Enaible CLI...");
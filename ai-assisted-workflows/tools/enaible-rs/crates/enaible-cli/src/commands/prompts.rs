@@ -1,14 +1,18 @@
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use enaible_core::load_workspace;
-use enaible_prompts::{lint_files, split_csv, PromptRenderer, CATALOG};
+use enaible_prompts::{lint_files, split_csv, PromptRenderer, VariableReport, CATALOG};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 #[derive(Subcommand)]
 pub enum PromptsCommands {
     /// List prompts known to the catalog
-    List,
+    List {
+        /// Also print each prompt's registered aliases
+        #[arg(long)]
+        aliases: bool,
+    },
 
     /// Render prompts for the selected systems
     Render {
@@ -16,13 +20,22 @@ pub enum PromptsCommands {
         #[arg(long, default_value = "all")]
         prompt: String,
 
-        /// Comma-separated system identifiers or 'all'
-        #[arg(long, default_value = "all")]
-        system: String,
+        /// Comma-separated system identifiers or 'all'. Falls back to the
+        /// workspace's `render.system` config when omitted, then to 'all'.
+        #[arg(long)]
+        system: Option<String>,
 
         /// Optional override directory for rendered output
         #[arg(short, long)]
         out: Option<PathBuf>,
+
+        /// Bypass the fingerprint cache and re-render everything
+        #[arg(long)]
+        force: bool,
+
+        /// Print a JSON render plan instead of writing any files
+        #[arg(long, conflicts_with = "force")]
+        build_plan: bool,
     },
 
     /// Show diffs between catalog output and current files
@@ -53,44 +66,73 @@ pub enum PromptsCommands {
         #[arg(long, default_value = "all")]
         prompt: String,
     },
+
+    /// Show the @TOKEN variables a prompt/system pair resolves, and flag
+    /// any that are unmapped or unused
+    Variables {
+        /// Comma-separated prompt identifiers or 'all'
+        #[arg(long, default_value = "all")]
+        prompt: String,
+
+        /// Comma-separated system identifiers or 'all'
+        #[arg(long, default_value = "all")]
+        system: String,
+
+        /// Emit the report as JSON instead of human-readable text
+        #[arg(long)]
+        json: bool,
+    },
 }
 
-pub fn handle_command(cmd: PromptsCommands) -> Result<()> {
+pub fn handle_command(cmd: PromptsCommands, start_dir: Option<&Path>) -> Result<()> {
     match cmd {
-        PromptsCommands::List => prompts_list(),
-        PromptsCommands::Render { prompt, system, out } => {
-            prompts_render(&prompt, &system, out)
+        PromptsCommands::List { aliases } => prompts_list(aliases, start_dir),
+        PromptsCommands::Render { prompt, system, out, force, build_plan } => {
+            prompts_render(&prompt, system, out, force, build_plan, start_dir)
+        }
+        PromptsCommands::Diff { prompt, system } => prompts_diff(&prompt, &system, start_dir),
+        PromptsCommands::Validate { prompt, system } => {
+            prompts_validate(&prompt, &system, start_dir)
+        }
+        PromptsCommands::Lint { prompt } => prompts_lint(&prompt, start_dir),
+        PromptsCommands::Variables { prompt, system, json } => {
+            prompts_variables(&prompt, &system, json, start_dir)
         }
-        PromptsCommands::Diff { prompt, system } => prompts_diff(&prompt, &system),
-        PromptsCommands::Validate { prompt, system } => prompts_validate(&prompt, &system),
-        PromptsCommands::Lint { prompt } => prompts_lint(&prompt),
     }
 }
 
-fn prompts_list() -> Result<()> {
-    let context = load_workspace(None)?;
+fn prompts_list(show_aliases: bool, start_dir: Option<&Path>) -> Result<()> {
+    let context = load_workspace(start_dir)?;
     let renderer = PromptRenderer::new(context)?;
 
     for definition in renderer.list_prompts() {
         let systems: Vec<String> = definition.systems.keys().cloned().collect();
-        println!(
+        print!(
             "{}: {} [{}]",
             definition.prompt_id,
             definition.title,
             systems.join(", ")
         );
+        if show_aliases && !definition.aliases.is_empty() {
+            print!(" (aliases: {})", definition.aliases.join(", "));
+        }
+        println!();
     }
 
     Ok(())
 }
 
-fn resolve_prompt_ids(prompts: &[String]) -> Result<Vec<String>> {
+fn resolve_prompt_ids(renderer: &PromptRenderer, prompts: &[String]) -> Result<Vec<String>> {
     if prompts.is_empty() || prompts == ["all"] {
         return Ok(CATALOG.keys().cloned().collect());
     }
 
     let catalog_ids: HashSet<_> = CATALOG.keys().cloned().collect();
-    let unknown: Vec<_> = prompts
+    let resolved: Vec<String> = prompts
+        .iter()
+        .map(|p| renderer.resolve_prompt_alias(p))
+        .collect();
+    let unknown: Vec<_> = resolved
         .iter()
         .filter(|p| !catalog_ids.contains(*p))
         .collect();
@@ -108,7 +150,7 @@ fn resolve_prompt_ids(prompts: &[String]) -> Result<Vec<String>> {
         );
     }
 
-    Ok(prompts.to_vec())
+    Ok(resolved)
 }
 
 fn resolve_systems(prompt_ids: &[String], systems: &[String]) -> Vec<String> {
@@ -148,43 +190,70 @@ fn build_overrides(
     overrides
 }
 
-fn prompts_render(prompts: &str, systems: &str, out: Option<PathBuf>) -> Result<()> {
-    let context = load_workspace(None)?;
+fn prompts_render(
+    prompts: &str,
+    systems: Option<String>,
+    out: Option<PathBuf>,
+    force: bool,
+    build_plan: bool,
+    start_dir: Option<&Path>,
+) -> Result<()> {
+    let context = load_workspace(start_dir)?;
+    let systems = systems
+        .or_else(|| context.config.render_defaults.system.clone())
+        .unwrap_or_else(|| "all".to_string());
     let renderer = PromptRenderer::new(context)?;
 
     let prompt_args = split_csv(prompts);
-    let system_args = split_csv(systems);
+    let system_args = split_csv(&systems);
 
-    let selected_prompts = resolve_prompt_ids(&prompt_args)?;
+    let selected_prompts = resolve_prompt_ids(&renderer, &prompt_args)?;
     let selected_systems = resolve_systems(&selected_prompts, &system_args);
     let overrides = build_overrides(&selected_systems, out);
 
-    let results = renderer.render(&selected_prompts, &selected_systems, Some(overrides))?;
+    if build_plan {
+        let plan = renderer.build_plan(&selected_prompts, &selected_systems, Some(overrides))?;
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+        return Ok(());
+    }
+
+    let results = renderer.render(&selected_prompts, &selected_systems, Some(overrides), force)?;
 
     for result in results {
         result.write()?;
-        println!(
-            "Rendered {} for {} → {}",
-            result.prompt_id,
-            result.system,
-            result.output_path.display()
-        );
+        if result.up_to_date {
+            println!(
+                "{} for {} is up to date → {}",
+                result.prompt_id,
+                result.system,
+                result.output_path.display()
+            );
+        } else {
+            println!(
+                "Rendered {} for {} → {}",
+                result.prompt_id,
+                result.system,
+                result.output_path.display()
+            );
+        }
     }
 
     Ok(())
 }
 
-fn prompts_diff(prompts: &str, systems: &str) -> Result<()> {
-    let context = load_workspace(None)?;
+fn prompts_diff(prompts: &str, systems: &str, start_dir: Option<&Path>) -> Result<()> {
+    let context = load_workspace(start_dir)?;
     let renderer = PromptRenderer::new(context)?;
 
     let prompt_args = split_csv(prompts);
     let system_args = split_csv(systems);
 
-    let selected_prompts = resolve_prompt_ids(&prompt_args)?;
+    let selected_prompts = resolve_prompt_ids(&renderer, &prompt_args)?;
     let selected_systems = resolve_systems(&selected_prompts, &system_args);
 
-    let results = renderer.render(&selected_prompts, &selected_systems, None)?;
+    // Diffing needs the real rendered content, so always bypass the
+    // fingerprint cache rather than risk comparing against stale content.
+    let results = renderer.render(&selected_prompts, &selected_systems, None, true)?;
 
     let mut has_diff = false;
     for result in results {
@@ -202,8 +271,8 @@ fn prompts_diff(prompts: &str, systems: &str) -> Result<()> {
     Ok(())
 }
 
-fn prompts_validate(prompts: &str, systems: &str) -> Result<()> {
-    match prompts_diff(prompts, systems) {
+fn prompts_validate(prompts: &str, systems: &str, start_dir: Option<&Path>) -> Result<()> {
+    match prompts_diff(prompts, systems, start_dir) {
         Ok(_) => Ok(()),
         Err(_) => {
             eprintln!("Prompt drift detected. Run `enaible prompts render` to update.");
@@ -212,11 +281,12 @@ fn prompts_validate(prompts: &str, systems: &str) -> Result<()> {
     }
 }
 
-fn prompts_lint(prompts: &str) -> Result<()> {
-    let context = load_workspace(None)?;
+fn prompts_lint(prompts: &str, start_dir: Option<&Path>) -> Result<()> {
+    let context = load_workspace(start_dir)?;
+    let renderer = PromptRenderer::new(context.clone())?;
 
     let prompt_args = split_csv(prompts);
-    let selected_prompts = resolve_prompt_ids(&prompt_args)?;
+    let selected_prompts = resolve_prompt_ids(&renderer, &prompt_args)?;
 
     // Collect unique source files for selected prompts
     let mut files = HashSet::new();
@@ -272,5 +342,78 @@ fn prompts_lint(prompts: &str) -> Result<()> {
         std::process::exit(1);
     }
 
+    Ok(())
+}
+
+fn print_variable_report(report: &VariableReport) {
+    println!("{} [{}]", report.prompt_id, report.system);
+    for variable in &report.variables {
+        let resolved = match (&variable.resolved_value, &variable.resolved_from) {
+            (Some(value), Some(from)) => format!("{} ({})", value, from),
+            (None, Some(from)) => format!("<{}>", from),
+            _ => "UNMAPPED".to_string(),
+        };
+        println!(
+            "  {} [{}{}] -> {}",
+            variable.name,
+            variable.kind,
+            if variable.required { "" } else { ", optional" },
+            resolved
+        );
+    }
+    if !report.unmapped.is_empty() {
+        println!("  unmapped: {}", report.unmapped.join(", "));
+    }
+    if !report.unused_metadata.is_empty() {
+        println!("  unused metadata: {}", report.unused_metadata.join(", "));
+    }
+}
+
+fn prompts_variables(
+    prompts: &str,
+    systems: &str,
+    json_output: bool,
+    start_dir: Option<&Path>,
+) -> Result<()> {
+    let context = load_workspace(start_dir)?;
+    let renderer = PromptRenderer::new(context)?;
+
+    let prompt_args = split_csv(prompts);
+    let system_args = split_csv(systems);
+
+    let selected_prompts = resolve_prompt_ids(&renderer, &prompt_args)?;
+    let selected_systems = resolve_systems(&selected_prompts, &system_args);
+
+    let mut reports = Vec::new();
+    for prompt_id in &selected_prompts {
+        let Some(definition) = enaible_prompts::CATALOG.get(prompt_id) else {
+            continue;
+        };
+        for system in &selected_systems {
+            if !definition.systems.contains_key(system) {
+                continue;
+            }
+            reports.push(renderer.inspect_variables(prompt_id, system)?);
+        }
+    }
+
+    let has_issues = reports
+        .iter()
+        .any(|r| !r.unmapped.is_empty() || !r.unused_metadata.is_empty());
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    } else if reports.is_empty() {
+        println!("No matching prompt/system pairs.");
+    } else {
+        for report in &reports {
+            print_variable_report(report);
+        }
+    }
+
+    if has_issues {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
\ No newline at end of file
@@ -1,17 +1,19 @@
 use anyhow::Result;
 use clap::Args;
 use enaible_core::{find_shared_root, load_workspace};
+use enaible_prompts::adapters::SYSTEM_CONTEXTS;
 use serde_json::json;
 use std::collections::HashMap;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Args)]
 pub struct ContextCaptureArgs {
-    /// Target platform to capture context for
+    /// Target platform to capture context for; auto-detected from configured
+    /// scope directories (e.g. `.claude`, `.codex`) when omitted
     #[arg(short, long)]
-    platform: String,
+    platform: Option<String>,
 
     /// Number of days to look back
     #[arg(long, default_value = "2")]
@@ -75,7 +77,72 @@ pub fn version() {
     println!("enaible {}", version);
 }
 
-pub fn doctor(json: bool) -> Result<()> {
+/// CLI binary invoked to report a given system's installed version.
+fn cli_binary_for(system: &str) -> Option<&'static str> {
+    match system {
+        "claude-code" => Some("claude"),
+        "codex" => Some("codex"),
+        "copilot" => Some("copilot"),
+        "cursor" => Some("cursor"),
+        "gemini" => Some("gemini"),
+        _ => None,
+    }
+}
+
+/// Invoke `<binary> --version` and parse the installed version out of stdout,
+/// returning `None` when the binary can't be spawned (i.e. not installed).
+fn detect_cli_version(binary: &str) -> Option<String> {
+    let output = Command::new(binary).arg("--version").output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let text = if text.trim().is_empty() {
+        String::from_utf8_lossy(&output.stderr).to_string()
+    } else {
+        text.to_string()
+    };
+
+    let first_line = text.lines().next()?.trim();
+    if first_line.is_empty() {
+        None
+    } else {
+        Some(first_line.to_string())
+    }
+}
+
+fn expand_user_scope_dir(user_scope_dir: &str) -> PathBuf {
+    if let Some(rest) = user_scope_dir.strip_prefix("~/") {
+        if let Some(home) = home::home_dir() {
+            return home.join(rest);
+        }
+    }
+    PathBuf::from(user_scope_dir)
+}
+
+struct SystemInfo {
+    name: String,
+    display_name: String,
+    project_scope_present: bool,
+    user_scope_present: bool,
+    cli_version: Option<String>,
+}
+
+fn collect_system_info(repo_root: &Path) -> Vec<SystemInfo> {
+    let mut names: Vec<_> = SYSTEM_CONTEXTS.keys().cloned().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter_map(|name| SYSTEM_CONTEXTS.get(&name).cloned())
+        .map(|ctx| SystemInfo {
+            project_scope_present: repo_root.join(&ctx.project_scope_dir).exists(),
+            user_scope_present: expand_user_scope_dir(&ctx.user_scope_dir).exists(),
+            cli_version: cli_binary_for(&ctx.name).and_then(detect_cli_version),
+            name: ctx.name,
+            display_name: ctx.display_name,
+        })
+        .collect()
+}
+
+pub fn doctor(json: bool, start_dir: Option<&Path>) -> Result<()> {
     let mut report = HashMap::new();
     let mut checks = HashMap::new();
     let mut errors = Vec::new();
@@ -114,13 +181,23 @@ pub fn doctor(json: bool) -> Result<()> {
     }
 
     // Check workspace
-    match load_workspace(None) {
+    let mut systems = Vec::new();
+    let mut config_sources: Vec<String> = Vec::new();
+    match load_workspace(start_dir) {
         Ok(context) => {
             checks.insert("workspace", true);
             report.insert("repo_root", context.repo_root.display().to_string());
 
             let schema_path = context.repo_root.join(".enaible").join("schema.json");
             checks.insert("schema_exists", schema_path.exists());
+
+            systems = collect_system_info(&context.repo_root);
+            config_sources = context
+                .config
+                .sources
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
         }
         Err(e) => {
             checks.insert("workspace", false);
@@ -138,6 +215,14 @@ pub fn doctor(json: bool) -> Result<()> {
             "errors": errors,
             "repo_root": report.get("repo_root"),
             "shared_root": report.get("shared_root"),
+            "systems": systems.iter().map(|s| json!({
+                "name": s.name,
+                "display_name": s.display_name,
+                "project_scope_present": s.project_scope_present,
+                "user_scope_present": s.user_scope_present,
+                "cli_version": s.cli_version,
+            })).collect::<Vec<_>>(),
+            "config_sources": config_sources,
         });
         println!("{}", serde_json::to_string_pretty(&json_report)?);
     } else {
@@ -161,6 +246,28 @@ pub fn doctor(json: bool) -> Result<()> {
                 println!("  - {}", err);
             }
         }
+
+        if !config_sources.is_empty() {
+            println!("Config layers (innermost first):");
+            for source in &config_sources {
+                println!("  - {}", source);
+            }
+        }
+
+        if !systems.is_empty() {
+            println!("Systems:");
+            for system in &systems {
+                let version = system.cli_version.as_deref().unwrap_or("not installed");
+                println!(
+                    "  {} ({}): project_scope={} user_scope={} cli={}",
+                    system.display_name,
+                    system.name,
+                    if system.project_scope_present { "yes" } else { "no" },
+                    if system.user_scope_present { "yes" } else { "no" },
+                    version,
+                );
+            }
+        }
     }
 
     if exit_code != 0 {
@@ -170,23 +277,70 @@ pub fn doctor(json: bool) -> Result<()> {
     Ok(())
 }
 
-pub fn context_capture(args: ContextCaptureArgs) -> Result<()> {
-    let workspace = load_workspace(None)?;
-
-    let script_path = match args.platform.as_str() {
-        "claude" => workspace
-            .repo_root
-            .join("shared")
-            .join("context")
-            .join("context_bundle_capture_claude.py"),
-        "codex" => workspace
-            .repo_root
-            .join("shared")
-            .join("context")
-            .join("context_bundle_capture_codex.py"),
-        _ => anyhow::bail!("Unknown platform: {}", args.platform),
+/// Platforms with a capture script under `shared/context/`, paired with the
+/// `SYSTEM_CONTEXTS` key used to detect whether the system is configured.
+const CAPTURE_PLATFORMS: &[(&str, &str)] = &[("claude", "claude-code"), ("codex", "codex")];
+
+fn capture_script_path(repo_root: &Path, platform: &str) -> Option<PathBuf> {
+    CAPTURE_PLATFORMS
+        .iter()
+        .find(|(name, _)| *name == platform)
+        .map(|_| {
+            repo_root
+                .join("shared")
+                .join("context")
+                .join(format!("context_bundle_capture_{}.py", platform))
+        })
+}
+
+/// Detect the single configured platform by checking which systems' project
+/// scope directories exist under `project_root` and have a matching capture
+/// script. Bails when none match, and with the candidate list when several do.
+fn detect_platform(repo_root: &Path, project_root: &Path) -> Result<String> {
+    let candidates: Vec<&str> = CAPTURE_PLATFORMS
+        .iter()
+        .filter(|(platform, system)| {
+            let scope_configured = SYSTEM_CONTEXTS
+                .get(*system)
+                .map(|ctx| project_root.join(&ctx.project_scope_dir).exists())
+                .unwrap_or(false);
+            scope_configured
+                && capture_script_path(repo_root, platform)
+                    .map(|p| p.exists())
+                    .unwrap_or(false)
+        })
+        .map(|(platform, _)| *platform)
+        .collect();
+
+    match candidates.as_slice() {
+        [] => anyhow::bail!(
+            "Could not auto-detect a platform under {}; pass --platform explicitly",
+            project_root.display()
+        ),
+        [only] => Ok(only.to_string()),
+        many => anyhow::bail!(
+            "Multiple configured platforms found ({}); pass --platform to disambiguate",
+            many.join(", ")
+        ),
+    }
+}
+
+pub fn context_capture(args: ContextCaptureArgs, start_dir: Option<&Path>) -> Result<()> {
+    let workspace = load_workspace(start_dir)?;
+
+    let project_root = args
+        .project_root
+        .clone()
+        .unwrap_or_else(|| workspace.repo_root.clone());
+
+    let platform = match &args.platform {
+        Some(platform) => platform.clone(),
+        None => detect_platform(&workspace.repo_root, &project_root)?,
     };
 
+    let script_path = capture_script_path(&workspace.repo_root, &platform)
+        .ok_or_else(|| anyhow::anyhow!("Unknown platform: {}", platform))?;
+
     if !script_path.exists() {
         anyhow::bail!("Context capture script not found at {}", script_path.display());
     }
@@ -232,8 +386,8 @@ pub fn context_capture(args: ContextCaptureArgs) -> Result<()> {
     Ok(())
 }
 
-pub fn docs_scrape(args: DocsScrapeArgs) -> Result<()> {
-    let workspace = load_workspace(None)?;
+pub fn docs_scrape(args: DocsScrapeArgs, start_dir: Option<&Path>) -> Result<()> {
+    let workspace = load_workspace(start_dir)?;
 
     let mut cmd = Command::new("python3");
     cmd.arg("-m").arg("web_scraper.cli");
@@ -268,8 +422,8 @@ pub fn docs_scrape(args: DocsScrapeArgs) -> Result<()> {
     Ok(())
 }
 
-pub fn auth_check(args: AuthCheckArgs) -> Result<()> {
-    let workspace = load_workspace(None)?;
+pub fn auth_check(args: AuthCheckArgs, start_dir: Option<&Path>) -> Result<()> {
+    let workspace = load_workspace(start_dir)?;
 
     let script = workspace
         .repo_root
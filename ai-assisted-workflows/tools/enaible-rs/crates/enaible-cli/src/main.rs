@@ -1,14 +1,20 @@
 mod commands;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
 use commands::{analyzers, install, prompts, root};
+use std::path::PathBuf;
 
 /// Unified CLI for AI-Assisted Workflows
 #[derive(Parser)]
 #[command(name = "enaible")]
 #[command(version, about, long_about = None)]
 struct Cli {
+    /// Run as if started in this directory, before workspace discovery
+    #[arg(short = 'C', long = "directory", global = true)]
+    directory: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -44,24 +50,102 @@ enum Commands {
 
     /// Verify that the requested CLI has an active authentication session
     AuthCheck(root::AuthCheckArgs),
+
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        shell: Shell,
+    },
+}
+
+/// Pull `-C`/`--directory`'s value out of `tokens` without invoking clap,
+/// returning the tokens with that flag (and its value) removed alongside
+/// the directory it named. `-C` is global, so clap accepts it on either
+/// side of the subcommand; this scans the whole slice rather than just a
+/// leading prefix, and the last occurrence wins, same as clap would see it.
+/// Supports `-C dir`, `-Cdir`, `--directory dir`, and `--directory=dir`.
+fn extract_directory_flag(tokens: &[String]) -> (Vec<String>, Option<PathBuf>) {
+    let mut remainder = Vec::with_capacity(tokens.len());
+    let mut dir = None;
+
+    let mut iter = tokens.iter();
+    while let Some(tok) = iter.next() {
+        if let Some(value) = tok.strip_prefix("--directory=") {
+            dir = Some(PathBuf::from(value));
+        } else if tok == "-C" || tok == "--directory" {
+            match iter.next() {
+                Some(value) => dir = Some(PathBuf::from(value)),
+                // Dangling flag with no value: leave it in `remainder` so
+                // clap still sees and rejects it, instead of this prescan
+                // silently swallowing it.
+                None => remainder.push(tok.clone()),
+            }
+        } else if let Some(value) = tok.strip_prefix("-C").filter(|v| !v.is_empty()) {
+            dir = Some(PathBuf::from(value));
+        } else {
+            remainder.push(tok.clone());
+        }
+    }
+
+    (remainder, dir)
+}
+
+/// Expand a user-typed subcommand through the `[alias]` table in
+/// `.enaible/config.json` before clap ever sees it, the same way cargo
+/// expands `[alias]` entries before dispatch. `-C`/`--directory` is pulled
+/// out first (both so it doesn't get mistaken for the alias head, and so
+/// the alias table itself is looked up rooted at that `-C`-aware directory
+/// rather than the process's own cwd — `enaible -C /other/repo scrape`
+/// expands using `/other/repo`'s aliases, not the caller's) and reinserted
+/// ahead of the resolved command so clap still sees it.
+fn expand_command_alias(argv: Vec<String>) -> Result<Vec<String>> {
+    if argv.len() < 2 {
+        return Ok(argv);
+    }
+
+    let (program, rest) = argv.split_first().expect("checked len >= 2");
+    let (rest, directory) = extract_directory_flag(rest);
+
+    let alias_lookup_dir = match &directory {
+        Some(dir) => dir.clone(),
+        None => std::env::current_dir()?,
+    };
+    let alias_config = enaible_core::load_alias_config(&alias_lookup_dir);
+    let resolved_rest = enaible_core::resolve_command_alias(&rest, &alias_config.alias)?;
+
+    let mut expanded = vec![program.clone()];
+    if let Some(dir) = directory {
+        expanded.push("--directory".to_string());
+        expanded.push(dir.to_string_lossy().into_owned());
+    }
+    expanded.extend(resolved_rest);
+    Ok(expanded)
 }
 
 fn main() -> Result<()> {
     env_logger::init();
 
-    let cli = Cli::parse();
+    let argv = expand_command_alias(std::env::args().collect())?;
+    let cli = Cli::parse_from(argv);
+    let start_dir = cli.directory.as_deref();
 
     match cli.command {
         Some(Commands::Version) | None => {
             root::version();
             Ok(())
         }
-        Some(Commands::Doctor { json }) => root::doctor(json),
-        Some(Commands::Prompts(cmd)) => prompts::handle_command(cmd),
-        Some(Commands::Analyzers(cmd)) => analyzers::handle_command(cmd),
-        Some(Commands::Install(args)) => install::handle_command(args),
-        Some(Commands::ContextCapture(args)) => root::context_capture(args),
-        Some(Commands::DocsScrape(args)) => root::docs_scrape(args),
-        Some(Commands::AuthCheck(args)) => root::auth_check(args),
+        Some(Commands::Doctor { json }) => root::doctor(json, start_dir),
+        Some(Commands::Prompts(cmd)) => prompts::handle_command(cmd, start_dir),
+        Some(Commands::Analyzers(cmd)) => analyzers::handle_command(cmd, start_dir),
+        Some(Commands::Install(args)) => install::handle_command(args, start_dir),
+        Some(Commands::ContextCapture(args)) => root::context_capture(args, start_dir),
+        Some(Commands::DocsScrape(args)) => root::docs_scrape(args, start_dir),
+        Some(Commands::AuthCheck(args)) => root::auth_check(args, start_dir),
+        Some(Commands::Completions { shell }) => {
+            let mut cmd = Cli::command();
+            let name = cmd.get_name().to_string();
+            clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
+            Ok(())
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use thiserror::Error;
+
+/// `[alias]`/`[system_alias]`/`[prompt_alias]` tables read from
+/// `.enaible/config.json`. See `resolve_command_alias` and
+/// `resolve_token_alias` for how each table gets applied.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AliasConfig {
+    #[serde(default)]
+    pub alias: HashMap<String, String>,
+    #[serde(default)]
+    pub system_alias: HashMap<String, String>,
+    #[serde(default)]
+    pub prompt_alias: HashMap<String, String>,
+}
+
+const CONFIG_RELATIVE: &str = ".enaible/config.json";
+
+/// Walk up from `start` looking for `.enaible/config.json` and return its
+/// alias tables. Returns empty tables when no config file is found.
+pub fn load_alias_config(start: &Path) -> AliasConfig {
+    let mut current = start;
+    loop {
+        let candidate = current.join(CONFIG_RELATIVE);
+        if candidate.is_file() {
+            return std::fs::read_to_string(&candidate)
+                .ok()
+                .and_then(|contents| serde_json::from_str(&contents).ok())
+                .unwrap_or_default();
+        }
+
+        match current.parent() {
+            Some(parent) => current = parent,
+            None => return AliasConfig::default(),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AliasError {
+    #[error("alias cycle detected resolving '{0}': '{1}' loops back on itself")]
+    Cycle(String, String),
+}
+
+/// Resolve a single token (e.g. a system name) through an alias map,
+/// following chains until a fixed point. `a -> b -> a` fails cleanly rather
+/// than looping.
+pub fn resolve_token_alias(token: &str, aliases: &HashMap<String, String>) -> Result<String, AliasError> {
+    let mut seen = HashSet::new();
+    seen.insert(token.to_string());
+    let mut current = token.to_string();
+
+    while let Some(next) = aliases.get(&current) {
+        if !seen.insert(next.clone()) {
+            return Err(AliasError::Cycle(token.to_string(), next.clone()));
+        }
+        current = next.clone();
+    }
+
+    Ok(current)
+}
+
+/// Resolve a command invocation's leading token through the `[alias]` table,
+/// splaying any trailing words the alias expands to (e.g. `scrape` ->
+/// `docs-scrape --verbose`) in front of the caller's own arguments.
+pub fn resolve_command_alias(
+    argv: &[String],
+    aliases: &HashMap<String, String>,
+) -> Result<Vec<String>, AliasError> {
+    let Some((head, rest)) = argv.split_first() else {
+        return Ok(argv.to_vec());
+    };
+
+    let mut seen = HashSet::new();
+    seen.insert(head.clone());
+    let mut current_head = head.clone();
+    let mut expanded_prefix: Vec<String> = Vec::new();
+
+    while let Some(expansion) = aliases.get(&current_head) {
+        let mut tokens = expansion.split_whitespace().map(str::to_string);
+        let Some(new_head) = tokens.next() else {
+            break;
+        };
+
+        if !seen.insert(new_head.clone()) {
+            return Err(AliasError::Cycle(head.clone(), expansion.clone()));
+        }
+
+        expanded_prefix.extend(tokens);
+        current_head = new_head;
+    }
+
+    let mut resolved = vec![current_head];
+    resolved.extend(expanded_prefix);
+    resolved.extend(rest.iter().cloned());
+    Ok(resolved)
+}
@@ -0,0 +1,143 @@
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE_NAME: &str = ".enaible.toml";
+
+/// On-disk shape of a single `.enaible.toml` layer.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ConfigFile {
+    shared_root: Option<PathBuf>,
+    artifacts_dir: Option<PathBuf>,
+    #[serde(default)]
+    default_analyzers: Vec<String>,
+    #[serde(default)]
+    workspace_members: Vec<PathBuf>,
+    confirm_tools: Option<String>,
+    render: Option<RenderDefaultsFile>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RenderDefaultsFile {
+    system: Option<String>,
+    #[serde(default)]
+    variables: HashMap<String, String>,
+}
+
+/// Project-local rendering defaults (`[render]` table) merged across layers.
+#[derive(Debug, Clone, Default)]
+pub struct RenderDefaults {
+    pub system: Option<String>,
+    pub variables: HashMap<String, String>,
+}
+
+/// The merged view of every `.enaible.toml` found between the resolved
+/// working directory and the repository root, innermost file wins. Env vars
+/// and CLI flags are overlaid by callers afterward and always take
+/// precedence over anything recorded here.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedConfig {
+    pub shared_root: Option<PathBuf>,
+    pub artifacts_dir: Option<PathBuf>,
+    pub default_analyzers: Vec<String>,
+    /// Declared monorepo sub-project paths, resolved relative to `repo_root`.
+    /// When non-empty, this overrides marker-file member discovery.
+    pub workspace_members: Vec<PathBuf>,
+    /// Regex (on the analyzer registry key) gating which tools require
+    /// interactive confirmation before running; overridden by `--confirm-tools`.
+    pub confirm_tools: Option<String>,
+    pub render_defaults: RenderDefaults,
+    /// Contributing files, innermost (closest to the working directory) first.
+    pub sources: Vec<PathBuf>,
+}
+
+/// Walk from `start` up to (and including) `repo_root`, collecting
+/// `.enaible.toml` paths. Canonicalizes before deduping so symlinked paths
+/// don't get visited twice, and never walks past the repo root boundary.
+fn discover_config_files(start: &Path, repo_root: &Path) -> Vec<PathBuf> {
+    let repo_root = repo_root.canonicalize().unwrap_or_else(|_| repo_root.to_path_buf());
+    let mut current = start.canonicalize().unwrap_or_else(|_| start.to_path_buf());
+
+    let mut files = Vec::new();
+    let mut seen = HashSet::new();
+
+    loop {
+        let candidate = current.join(CONFIG_FILE_NAME);
+        if candidate.is_file() {
+            if let Ok(canonical) = candidate.canonicalize() {
+                if seen.insert(canonical.clone()) {
+                    files.push(canonical);
+                }
+            }
+        }
+
+        if current == repo_root {
+            break;
+        }
+
+        match current.parent() {
+            Some(parent) => current = parent.to_path_buf(),
+            None => break,
+        }
+    }
+
+    files
+}
+
+fn resolve_relative(path: &Path, repo_root: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        repo_root.join(path)
+    }
+}
+
+/// Load and merge every `.enaible.toml` between `start` and `repo_root`,
+/// innermost-wins, modeled on cargo's hierarchical `config.toml` discovery.
+pub fn load_layered_config(start: &Path, repo_root: &Path) -> ResolvedConfig {
+    let files = discover_config_files(start, repo_root);
+
+    let mut resolved = ResolvedConfig::default();
+
+    // `files` is innermost-first; apply outermost-to-innermost so later
+    // (more specific) layers override earlier ones.
+    for path in files.iter().rev() {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let Ok(parsed) = toml::from_str::<ConfigFile>(&contents) else {
+            continue;
+        };
+
+        if let Some(shared_root) = parsed.shared_root {
+            resolved.shared_root = Some(resolve_relative(&shared_root, repo_root));
+        }
+        if let Some(artifacts_dir) = parsed.artifacts_dir {
+            resolved.artifacts_dir = Some(resolve_relative(&artifacts_dir, repo_root));
+        }
+        if !parsed.default_analyzers.is_empty() {
+            resolved.default_analyzers = parsed.default_analyzers;
+        }
+        if !parsed.workspace_members.is_empty() {
+            resolved.workspace_members = parsed
+                .workspace_members
+                .into_iter()
+                .map(|member| resolve_relative(&member, repo_root))
+                .collect();
+        }
+        if let Some(confirm_tools) = parsed.confirm_tools {
+            resolved.confirm_tools = Some(confirm_tools);
+        }
+        if let Some(render) = parsed.render {
+            if render.system.is_some() {
+                resolved.render_defaults.system = render.system;
+            }
+            resolved.render_defaults.variables.extend(render.variables);
+        }
+
+        resolved.sources.push(path.clone());
+    }
+
+    resolved.sources.reverse();
+    resolved
+}
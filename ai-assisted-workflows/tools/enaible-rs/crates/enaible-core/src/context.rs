@@ -1,3 +1,5 @@
+use crate::config::{self, ResolvedConfig};
+use std::collections::HashSet;
 use std::env;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -8,6 +10,19 @@ pub struct WorkspaceContext {
     pub repo_root: PathBuf,
     pub shared_root: PathBuf,
     pub artifacts_root: PathBuf,
+    pub config: ResolvedConfig,
+    pub members: Vec<WorkspaceMember>,
+}
+
+/// A discovered sub-project within a monorepo, identified either by a
+/// declared `workspace_members` entry in `.enaible.toml` or by a package
+/// manifest marker (`package.json`, `pyproject.toml`, `Cargo.toml`).
+#[derive(Debug, Clone)]
+pub struct WorkspaceMember {
+    /// Path relative to `repo_root`, using `/` separators.
+    pub name: String,
+    pub path: PathBuf,
+    pub marker: String,
 }
 
 #[derive(Error, Debug)]
@@ -112,9 +127,97 @@ fn find_repo_root(start: Option<&Path>, require_shared: bool) -> Result<PathBuf,
     Err(WorkspaceError::RepoRootNotFound)
 }
 
-/// Resolve artifacts root directory
-fn resolve_artifacts_root(repo_root: &Path) -> PathBuf {
+const MEMBER_MARKERS: &[&str] = &["package.json", "pyproject.toml", "Cargo.toml"];
+const MEMBER_SKIP_DIRS: &[&str] = &[
+    ".git", "node_modules", "target", "vendor", "dist", "build", ".enaible",
+];
+const MEMBER_MAX_DEPTH: usize = 4;
+
+fn member_name(path: &Path, repo_root: &Path) -> String {
+    path.strip_prefix(repo_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Recursively look for directories containing a package manifest marker,
+/// skipping common build/vendor directories. Bounded by `MEMBER_MAX_DEPTH` so
+/// a large monorepo doesn't trigger a full filesystem crawl.
+fn visit_dir(
+    dir: &Path,
+    repo_root: &Path,
+    depth: usize,
+    seen: &mut HashSet<PathBuf>,
+    members: &mut Vec<WorkspaceMember>,
+) {
+    if depth > MEMBER_MAX_DEPTH {
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut marker = None;
+    let mut subdirs = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        if path.is_dir() {
+            if !MEMBER_SKIP_DIRS.contains(&name) {
+                subdirs.push(path);
+            }
+        } else if MEMBER_MARKERS.contains(&name) {
+            marker = Some(name.to_string());
+        }
+    }
+
+    if let Some(marker) = marker {
+        if dir != repo_root && seen.insert(dir.to_path_buf()) {
+            members.push(WorkspaceMember {
+                name: member_name(dir, repo_root),
+                path: dir.to_path_buf(),
+                marker,
+            });
+        }
+    }
+
+    for subdir in subdirs {
+        visit_dir(&subdir, repo_root, depth + 1, seen, members);
+    }
+}
+
+/// Discover workspace members. An explicit `workspace_members` list in
+/// `.enaible.toml` always takes precedence over marker-file discovery.
+pub fn discover_members(repo_root: &Path, config: &ResolvedConfig) -> Vec<WorkspaceMember> {
+    if !config.workspace_members.is_empty() {
+        return config
+            .workspace_members
+            .iter()
+            .map(|path| WorkspaceMember {
+                name: member_name(path, repo_root),
+                path: path.clone(),
+                marker: "declared".to_string(),
+            })
+            .collect();
+    }
+
+    let mut members = Vec::new();
+    let mut seen = HashSet::new();
+    visit_dir(repo_root, repo_root, 0, &mut seen, &mut members);
+    members.sort_by(|a, b| a.name.cmp(&b.name));
+    members
+}
+
+/// Resolve artifacts root directory, falling back to any `.enaible.toml`
+/// layer's `artifacts_dir` before the repo-relative default. Env vars always
+/// win over file-declared values.
+fn resolve_artifacts_root(repo_root: &Path, config: &ResolvedConfig) -> PathBuf {
     env_path(&["ENAIBLE_ARTIFACTS_DIR", "ENAIBLE_ARTIFACTS_ROOT"])
+        .or_else(|| config.artifacts_dir.clone())
         .unwrap_or_else(|| repo_root.join(".enaible"))
 }
 
@@ -125,10 +228,19 @@ pub fn load_workspace(start: Option<&Path>) -> Result<WorkspaceContext, Workspac
     // If we have a packaged/shared copy, we can relax repo discovery
     let repo_root = find_repo_root(start, shared_root.is_none())?;
 
+    let search_start = match start {
+        Some(p) => p.to_path_buf(),
+        None => env::current_dir()?,
+    };
+    let config = config::load_layered_config(&search_start, &repo_root);
+
     let shared_root = match shared_root {
         Some(root) => root,
         None => {
-            let root = repo_root.join("shared");
+            let root = config
+                .shared_root
+                .clone()
+                .unwrap_or_else(|| repo_root.join("shared"));
             if !root.exists() {
                 return Err(WorkspaceError::SharedRootMissing(root));
             }
@@ -136,14 +248,18 @@ pub fn load_workspace(start: Option<&Path>) -> Result<WorkspaceContext, Workspac
         }
     };
 
-    let artifacts_root = resolve_artifacts_root(&repo_root);
+    let artifacts_root = resolve_artifacts_root(&repo_root, &config);
 
     // Create artifacts directory if it doesn't exist
     std::fs::create_dir_all(&artifacts_root).ok();
 
+    let members = discover_members(&repo_root, &config);
+
     Ok(WorkspaceContext {
         repo_root,
         shared_root,
         artifacts_root,
+        config,
+        members,
     })
 }
\ No newline at end of file
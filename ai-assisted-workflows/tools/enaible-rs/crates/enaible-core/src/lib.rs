@@ -1,5 +1,11 @@
+pub mod alias;
+pub mod config;
 pub mod constants;
 pub mod context;
 
+pub use alias::{load_alias_config, resolve_command_alias, resolve_token_alias, AliasConfig, AliasError};
+pub use config::{RenderDefaults, ResolvedConfig};
 pub use constants::MANAGED_SENTINEL;
-pub use context::{WorkspaceContext, WorkspaceError, load_workspace, find_shared_root};
\ No newline at end of file
+pub use context::{
+    WorkspaceContext, WorkspaceError, WorkspaceMember, load_workspace, find_shared_root,
+};
\ No newline at end of file
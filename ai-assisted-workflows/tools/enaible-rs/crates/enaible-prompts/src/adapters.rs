@@ -1,9 +1,10 @@
 use anyhow::{anyhow, Result};
 use once_cell::sync::Lazy;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemRenderContext {
     pub name: String,
     pub display_name: String,
@@ -98,9 +99,72 @@ pub static SYSTEM_CONTEXTS: Lazy<HashMap<String, SystemRenderContext>> = Lazy::n
     contexts
 });
 
-pub fn get_system_context(system: &str) -> Result<SystemRenderContext> {
-    SYSTEM_CONTEXTS
-        .get(system)
-        .cloned()
-        .ok_or_else(|| anyhow!("Unknown system: {}", system))
+/// On-disk descriptor for custom systems, e.g. `enaible-systems.json`:
+/// `{ "systems": [ { "name": "house-agent", ... } ] }`.
+#[derive(Debug, Deserialize)]
+struct SystemRegistryFile {
+    #[serde(default)]
+    systems: Vec<SystemRenderContext>,
+}
+
+/// Discovers `SystemRenderContext` entries declared outside the compiled
+/// binary, mirroring how rust-analyzer reads a `project.json` descriptor
+/// instead of hardcoding workspace layout.
+pub struct SystemRegistry;
+
+impl SystemRegistry {
+    const FILE_NAME: &'static str = "enaible-systems.json";
+
+    /// Walk up from `start` looking for the registry file and return the
+    /// custom systems it declares. Returns an empty list when absent or
+    /// unparseable so callers can fall back to the built-in defaults.
+    fn discover(start: &Path) -> Vec<SystemRenderContext> {
+        let mut current = start;
+        loop {
+            let candidate = current.join(Self::FILE_NAME);
+            if candidate.is_file() {
+                return std::fs::read_to_string(&candidate)
+                    .ok()
+                    .and_then(|contents| serde_json::from_str::<SystemRegistryFile>(&contents).ok())
+                    .map(|file| file.systems)
+                    .unwrap_or_default();
+            }
+
+            match current.parent() {
+                Some(parent) => current = parent,
+                None => return Vec::new(),
+            }
+        }
+    }
+}
+
+/// Resolve a system's render context, merging any custom systems declared
+/// under `repo_root` over the built-in `SYSTEM_CONTEXTS` (user file wins on
+/// a name collision).
+pub fn get_system_context(system: &str, repo_root: &Path) -> Result<SystemRenderContext> {
+    let alias_config = enaible_core::load_alias_config(repo_root);
+    let system = enaible_core::resolve_token_alias(system, &alias_config.system_alias)?;
+    let system = system.as_str();
+
+    let custom = SystemRegistry::discover(repo_root);
+    if let Some(found) = custom.into_iter().find(|ctx| ctx.name == system) {
+        return Ok(found);
+    }
+
+    SYSTEM_CONTEXTS.get(system).cloned().ok_or_else(|| {
+        let suggestion = crate::utils::find_suggestion(
+            system,
+            SYSTEM_CONTEXTS.keys().map(String::as_str),
+            3,
+        );
+
+        match suggestion {
+            Some(candidate) => anyhow!(
+                "Unknown system: {}; did you mean '{}'?",
+                system,
+                candidate
+            ),
+            None => anyhow!("Unknown system: {}", system),
+        }
+    })
 }
@@ -16,6 +16,10 @@ pub struct PromptDefinition {
     pub source_path: PathBuf,
     pub title: String,
     pub systems: HashMap<String, SystemPromptConfig>,
+    /// Built-in short names that resolve to `prompt_id`, e.g. `analyze-sec`
+    /// for `analyze-security`. Merged with user-declared aliases at lookup
+    /// time (see `resolve_prompt_alias`), user aliases taking precedence.
+    pub aliases: Vec<String>,
 }
 
 fn repo_path(parts: &[&str]) -> PathBuf {
@@ -31,6 +35,7 @@ pub static CATALOG: Lazy<HashMap<String, PromptDefinition>> = Lazy::new(|| {
             prompt_id: "analyze-security".to_string(),
             source_path: repo_path(&["shared", "prompts", "analyze-security.md"]),
             title: "analyze-security v1.0".to_string(),
+            aliases: vec!["analyze-sec".to_string(), "sec-scan".to_string()],
             systems: {
                 let mut systems = HashMap::new();
 
@@ -160,6 +165,7 @@ pub static CATALOG: Lazy<HashMap<String, PromptDefinition>> = Lazy::new(|| {
             prompt_id: "analyze-architecture".to_string(),
             source_path: repo_path(&["shared", "prompts", "analyze-architecture.md"]),
             title: "analyze-architecture v1.0".to_string(),
+            aliases: vec!["analyze-arch".to_string()],
             systems: HashMap::new(), // Would be populated similar to above
         },
     );
@@ -170,9 +176,23 @@ pub static CATALOG: Lazy<HashMap<String, PromptDefinition>> = Lazy::new(|| {
             prompt_id: "analyze-repository".to_string(),
             source_path: repo_path(&["shared", "prompts", "analyze-repository.md"]),
             title: "Repository Analysis v1.0".to_string(),
+            aliases: vec!["analyze-repo".to_string()],
             systems: HashMap::new(),
         },
     );
 
     catalog
+});
+
+/// Built-in alias -> canonical `prompt_id` map, flattened from each
+/// definition's `aliases`. User-declared aliases from workspace config take
+/// precedence over these at lookup time.
+pub static PROMPT_ALIASES: Lazy<HashMap<String, String>> = Lazy::new(|| {
+    let mut aliases = HashMap::new();
+    for definition in CATALOG.values() {
+        for alias in &definition.aliases {
+            aliases.insert(alias.clone(), definition.prompt_id.clone());
+        }
+    }
+    aliases
 });
\ No newline at end of file
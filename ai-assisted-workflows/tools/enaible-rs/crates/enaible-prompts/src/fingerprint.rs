@@ -0,0 +1,78 @@
+use crate::adapters::SystemRenderContext;
+use crate::catalog::{PromptDefinition, SystemPromptConfig};
+use anyhow::{Context, Result};
+use enaible_core::MANAGED_SENTINEL;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Cargo-style fingerprint cache for rendered prompts: `PromptRenderer::render`
+/// skips the Jinja pass entirely for a `(prompt, system)` pair whose stored
+/// fingerprint still matches and whose output file is still on disk.
+pub struct FingerprintStore {
+    fingerprints_dir: PathBuf,
+}
+
+impl FingerprintStore {
+    pub fn new(repo_root: &Path) -> Self {
+        Self {
+            fingerprints_dir: repo_root.join(".build").join(".fingerprints"),
+        }
+    }
+
+    pub fn fingerprint_path(&self, prompt_id: &str, system: &str) -> PathBuf {
+        self.fingerprints_dir
+            .join(format!("{}-{}.fp", prompt_id, system))
+    }
+
+    /// Hash every input that affects a render's output: the source prompt
+    /// file, the resolved wrapper template file, the serialized system
+    /// context, the config's frontmatter/metadata, and the managed-file
+    /// sentinel (so a sentinel bump invalidates every cached output).
+    pub fn compute(
+        &self,
+        repo_root: &Path,
+        definition: &PromptDefinition,
+        config: &SystemPromptConfig,
+        system_context: &SystemRenderContext,
+    ) -> Result<String> {
+        let source_path = repo_root.join(&definition.source_path);
+        let source_bytes = fs::read(&source_path)
+            .with_context(|| format!("Failed to read {} for fingerprint", source_path.display()))?;
+
+        let template_path = repo_root.join(&config.template);
+        let template_bytes = fs::read(&template_path)
+            .with_context(|| format!("Failed to read {} for fingerprint", template_path.display()))?;
+
+        let system_context_json = serde_json::to_string(system_context)?;
+        let frontmatter_json = serde_json::to_string(&config.frontmatter)?;
+        let metadata_json = serde_json::to_string(&config.metadata)?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(&source_bytes);
+        hasher.update(&template_bytes);
+        hasher.update(system_context_json.as_bytes());
+        hasher.update(frontmatter_json.as_bytes());
+        hasher.update(metadata_json.as_bytes());
+        hasher.update(MANAGED_SENTINEL.as_bytes());
+
+        Ok(to_hex(&hasher.finalize()))
+    }
+
+    /// Whether `fingerprint` matches the value stored at `fingerprint_path`
+    /// and the output file it describes is still present (a deleted output
+    /// must always be re-rendered).
+    pub fn is_fresh(&self, fingerprint_path: &Path, fingerprint: &str, output_path: &Path) -> bool {
+        if !output_path.exists() {
+            return false;
+        }
+
+        fs::read_to_string(fingerprint_path)
+            .map(|stored| stored.trim() == fingerprint)
+            .unwrap_or(false)
+    }
+}
@@ -1,10 +1,17 @@
 pub mod adapters;
 pub mod catalog;
+pub mod fingerprint;
 pub mod lint;
 pub mod renderer;
 pub mod utils;
 
-pub use catalog::{PromptDefinition, SystemPromptConfig, CATALOG};
+pub use catalog::{PromptDefinition, SystemPromptConfig, CATALOG, PROMPT_ALIASES};
 pub use lint::{lint_files, LintIssue};
-pub use renderer::{PromptRenderer, RenderResult};
-pub use utils::{extract_variables, split_csv, VariableSpec};
\ No newline at end of file
+pub use renderer::{
+    PromptRenderer, RenderInvocation, RenderPlan, RenderResult, RenderStatus, VariableReport,
+    VariableResolution,
+};
+pub use utils::{
+    extract_variables, find_suggestion, find_suggestions, lev_distance, split_csv, DiagnosticKind,
+    VariableDiagnostic, VariableSpec,
+};
\ No newline at end of file
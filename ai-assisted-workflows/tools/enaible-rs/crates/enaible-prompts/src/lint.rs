@@ -1,3 +1,4 @@
+use crate::utils::find_suggestion;
 use anyhow::Result;
 use regex::Regex;
 use std::collections::HashSet;
@@ -35,12 +36,23 @@ pub fn lint_files(files: &HashSet<PathBuf>) -> Result<Vec<LintIssue>> {
 
                 // Check for common typos
                 let common_tokens = ["TARGET", "VERBOSE", "OPTIONAL", "PATH", "FILE"];
-                if !common_tokens.iter().any(|t| token.contains(t)) && token.len() < 3 {
-                    issues.push(LintIssue {
-                        path: file_path.display().to_string(),
-                        line: line_num + 1,
-                        message: format!("Suspicious token '{}' - possibly too short", token),
-                    });
+                if !common_tokens.iter().any(|t| token.contains(t)) {
+                    if let Some(candidate) = find_suggestion(token, common_tokens.iter().copied(), 3) {
+                        issues.push(LintIssue {
+                            path: file_path.display().to_string(),
+                            line: line_num + 1,
+                            message: format!(
+                                "Suspicious token '{}' - did you mean '{}'?",
+                                token, candidate
+                            ),
+                        });
+                    } else if token.len() < 3 {
+                        issues.push(LintIssue {
+                            path: file_path.display().to_string(),
+                            line: line_num + 1,
+                            message: format!("Suspicious token '{}' - possibly too short", token),
+                        });
+                    }
                 }
             }
 
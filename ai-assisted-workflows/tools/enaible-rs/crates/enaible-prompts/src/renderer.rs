@@ -1,33 +1,117 @@
 use crate::adapters::{get_system_context, SystemRenderContext};
-use crate::catalog::{PromptDefinition, SystemPromptConfig, CATALOG};
-use crate::utils::{extract_variables, VariableSpec};
+use crate::catalog::{PromptDefinition, SystemPromptConfig, CATALOG, PROMPT_ALIASES};
+use crate::fingerprint::FingerprintStore;
+use crate::utils::{extract_variables, find_suggestions, VariableDiagnostic, VariableSpec};
 use anyhow::{Context, Result};
 use enaible_core::{WorkspaceContext, MANAGED_SENTINEL};
 use minijinja::{context, Environment, Value};
 use serde::Serialize;
 use similar::{ChangeTag, TextDiff};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 
+/// A single `@TOKEN` found in a rendered prompt body, together with the
+/// value `PromptRenderer` would resolve it to (if any) and where that value
+/// came from.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariableResolution {
+    pub name: String,
+    pub kind: String,
+    pub required: bool,
+    pub resolved_value: Option<String>,
+    pub resolved_from: Option<String>,
+}
+
+/// Report produced by `PromptRenderer::inspect_variables` for a single
+/// prompt/system pair.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariableReport {
+    pub prompt_id: String,
+    pub system: String,
+    pub variables: Vec<VariableResolution>,
+    /// Tokens referenced in the source that none of `metadata`, the
+    /// workspace's `render.variables` defaults, or the system context could
+    /// resolve. Positional and flag tokens are never listed here, since they
+    /// are supplied as CLI arguments.
+    pub unmapped: Vec<String>,
+    /// `metadata` keys configured for this system that no token references.
+    pub unused_metadata: Vec<String>,
+}
+
+/// What writing a `RenderInvocation`'s output would do to the file at its
+/// `output_path`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderStatus {
+    Create,
+    Overwrite,
+    Unchanged,
+}
+
+/// One `(prompt, system)` render a plan would perform, without performing it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RenderInvocation {
+    pub prompt_id: String,
+    pub system: String,
+    pub output_path: PathBuf,
+    pub template: String,
+    pub variables: Vec<VariableSpec>,
+    pub argument_hint: String,
+    pub status: RenderStatus,
+}
+
+/// Machine-readable dry-run output of `PromptRenderer::build_plan`, modeled
+/// on cargo's `SerializedBuildPlan` — describes what a real render would do
+/// without writing anything, so CI and editors can gate on it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RenderPlan {
+    pub invocations: Vec<RenderInvocation>,
+}
+
 #[derive(Debug, Clone)]
 pub struct RenderResult {
     pub prompt_id: String,
     pub system: String,
     pub content: String,
     pub output_path: PathBuf,
+    /// `true` when the fingerprint cache found this output already current
+    /// and skipped the Jinja render entirely; `content` is empty in that
+    /// case since it was never recomputed.
+    pub up_to_date: bool,
+    /// The fingerprint this render's content was computed from, and where to
+    /// persist it. Recorded only once `write()` actually lands `content` at
+    /// `output_path`, so a command that renders without writing (`diff`,
+    /// `validate`) can't mark a stale output fresh.
+    fingerprint: Option<(PathBuf, String)>,
 }
 
 impl RenderResult {
     pub fn write(&self) -> Result<()> {
+        if self.up_to_date {
+            return Ok(());
+        }
+
         if let Some(parent) = self.output_path.parent() {
             fs::create_dir_all(parent)?;
         }
         fs::write(&self.output_path, &self.content)?;
+
+        if let Some((fingerprint_path, fingerprint)) = &self.fingerprint {
+            if let Some(parent) = fingerprint_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(fingerprint_path, fingerprint)?;
+        }
+
         Ok(())
     }
 
     pub fn diff(&self) -> Result<String> {
+        if self.up_to_date {
+            return Ok(String::new());
+        }
+
         if !self.output_path.exists() {
             return Ok(String::new());
         }
@@ -89,52 +173,268 @@ impl PromptRenderer {
         CATALOG.values().cloned().collect()
     }
 
+    /// Resolve `prompt_id` through the alias tables: user-declared aliases
+    /// from `.enaible/config.json` first, then the catalog's built-in
+    /// `aliases`, falling back to `prompt_id` itself unchanged.
+    pub fn resolve_prompt_alias(&self, prompt_id: &str) -> String {
+        let alias_config = enaible_core::load_alias_config(&self.context.repo_root);
+        if let Some(canonical) = alias_config.prompt_alias.get(prompt_id) {
+            return canonical.clone();
+        }
+        PROMPT_ALIASES
+            .get(prompt_id)
+            .cloned()
+            .unwrap_or_else(|| prompt_id.to_string())
+    }
+
     pub fn render(
         &self,
         prompt_ids: &[String],
         systems: &[String],
         output_override: Option<HashMap<String, Option<PathBuf>>>,
+        force: bool,
     ) -> Result<Vec<RenderResult>> {
         let mut results = Vec::new();
 
+        let known_systems = known_systems();
+        let fingerprints = FingerprintStore::new(&self.context.repo_root);
+
         for prompt_id in prompt_ids {
+            let resolved_id = self.resolve_prompt_alias(prompt_id);
             let definition = CATALOG
-                .get(prompt_id)
-                .ok_or_else(|| anyhow::anyhow!("Unknown prompt: {}", prompt_id))?;
+                .get(&resolved_id)
+                .ok_or_else(|| unknown_prompt_error(prompt_id))?;
 
             for system in systems {
                 let Some(config) = definition.systems.get(system) else {
+                    // A system simply unsupported by this particular prompt is
+                    // expected (e.g. rendering `--system all` across prompts
+                    // with different system coverage); only a system unknown
+                    // to every prompt in the catalog is an actual typo.
+                    if !known_systems.contains(system.as_str()) {
+                        return Err(unknown_system_error(system, &known_systems));
+                    }
                     continue;
                 };
 
-                let system_context = get_system_context(system)?;
+                let system_context = get_system_context(system, &self.context.repo_root)?;
+                let output_path = self.resolve_output_path(
+                    config,
+                    system,
+                    output_override.as_ref(),
+                );
+
+                let fingerprint =
+                    fingerprints.compute(&self.context.repo_root, definition, config, &system_context)?;
+                let fingerprint_path = fingerprints.fingerprint_path(&resolved_id, system);
+
+                if !force && fingerprints.is_fresh(&fingerprint_path, &fingerprint, &output_path) {
+                    results.push(RenderResult {
+                        prompt_id: resolved_id.clone(),
+                        system: system.clone(),
+                        content: String::new(),
+                        output_path,
+                        up_to_date: true,
+                        fingerprint: None,
+                    });
+                    continue;
+                }
+
                 let rendered_body = self.render_body(definition, &system_context, config)?;
-                let (variables, stripped_body) = extract_variables(&rendered_body);
+                let (variables, diagnostics, stripped_body) = extract_variables(&rendered_body);
 
                 let content = self.render_wrapper(
                     definition,
                     &system_context,
                     config,
+                    &rendered_body,
                     &stripped_body,
                     &variables,
+                    &diagnostics,
                 )?;
 
+                results.push(RenderResult {
+                    prompt_id: resolved_id.clone(),
+                    system: system.clone(),
+                    content: ensure_trailing_newline(content),
+                    output_path,
+                    up_to_date: false,
+                    fingerprint: Some((fingerprint_path, fingerprint)),
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Render every selected `(prompt, system)` pair without writing
+    /// anything, returning a `RenderPlan` describing what a real render
+    /// would do to each `output_path` (`Create`/`Overwrite`/`Unchanged`,
+    /// judged by comparing rendered content against the file on disk).
+    pub fn build_plan(
+        &self,
+        prompt_ids: &[String],
+        systems: &[String],
+        output_override: Option<HashMap<String, Option<PathBuf>>>,
+    ) -> Result<RenderPlan> {
+        let mut invocations = Vec::new();
+        let known_systems = known_systems();
+
+        for prompt_id in prompt_ids {
+            let resolved_id = self.resolve_prompt_alias(prompt_id);
+            let definition = CATALOG
+                .get(&resolved_id)
+                .ok_or_else(|| unknown_prompt_error(prompt_id))?;
+
+            for system in systems {
+                let Some(config) = definition.systems.get(system) else {
+                    if !known_systems.contains(system.as_str()) {
+                        return Err(unknown_system_error(system, &known_systems));
+                    }
+                    continue;
+                };
+
+                let system_context = get_system_context(system, &self.context.repo_root)?;
+                let rendered_body = self.render_body(definition, &system_context, config)?;
+                let (variables, diagnostics, stripped_body) = extract_variables(&rendered_body);
+
+                let content = self.render_wrapper(
+                    definition,
+                    &system_context,
+                    config,
+                    &rendered_body,
+                    &stripped_body,
+                    &variables,
+                    &diagnostics,
+                )?;
+                let content = ensure_trailing_newline(content);
+
                 let output_path = self.resolve_output_path(
                     config,
                     system,
                     output_override.as_ref(),
                 );
 
-                results.push(RenderResult {
-                    prompt_id: prompt_id.clone(),
+                let status = if !output_path.exists() {
+                    RenderStatus::Create
+                } else {
+                    let current = fs::read_to_string(&output_path).unwrap_or_default();
+                    if current == content {
+                        RenderStatus::Unchanged
+                    } else {
+                        RenderStatus::Overwrite
+                    }
+                };
+
+                invocations.push(RenderInvocation {
+                    prompt_id: resolved_id.clone(),
                     system: system.clone(),
-                    content: ensure_trailing_newline(content),
                     output_path,
+                    template: config.template.clone(),
+                    argument_hint: argument_hint_from_variables(&variables),
+                    variables,
+                    status,
                 });
             }
         }
 
-        Ok(results)
+        Ok(RenderPlan { invocations })
+    }
+
+    /// Report every `@TOKEN` referenced by `prompt_id`'s `system` source,
+    /// what `PromptRenderer` resolves it to (the per-system `metadata` table,
+    /// then the workspace's `render.variables` defaults, then the system's
+    /// render context; positional and flag tokens left over are marked as
+    /// CLI arguments), and which tokens/metadata entries don't line up with
+    /// each other.
+    pub fn inspect_variables(&self, prompt_id: &str, system: &str) -> Result<VariableReport> {
+        let resolved_id = self.resolve_prompt_alias(prompt_id);
+        let definition = CATALOG
+            .get(&resolved_id)
+            .ok_or_else(|| anyhow::anyhow!("Unknown prompt: {}", prompt_id))?;
+        let config = definition.systems.get(system).ok_or_else(|| {
+            anyhow::anyhow!("Prompt '{}' has no '{}' system", prompt_id, system)
+        })?;
+
+        let system_context = get_system_context(system, &self.context.repo_root)?;
+        let rendered_body = self.render_body(definition, &system_context, config)?;
+        let (variables, _diagnostics, _stripped) = extract_variables(&rendered_body);
+
+        let system_context_value = serde_json::to_value(&system_context)?;
+        let mut used_metadata = HashSet::new();
+        let mut unmapped = Vec::new();
+
+        let resolutions: Vec<VariableResolution> = variables
+            .iter()
+            .map(|var| {
+                let key = var.name.trim_start_matches('@').to_lowercase();
+
+                if let Some(value) = config.metadata.get(&key) {
+                    used_metadata.insert(key.clone());
+                    return VariableResolution {
+                        name: var.name.clone(),
+                        kind: var.kind.clone(),
+                        required: var.required,
+                        resolved_value: Some(value.clone()),
+                        resolved_from: Some("metadata".to_string()),
+                    };
+                }
+
+                if let Some(value) = self.context.config.render_defaults.variables.get(&key) {
+                    return VariableResolution {
+                        name: var.name.clone(),
+                        kind: var.kind.clone(),
+                        required: var.required,
+                        resolved_value: Some(value.clone()),
+                        resolved_from: Some("render-defaults".to_string()),
+                    };
+                }
+
+                if let Some(value) = system_context_value.get(&key).and_then(|v| v.as_str()) {
+                    return VariableResolution {
+                        name: var.name.clone(),
+                        kind: var.kind.clone(),
+                        required: var.required,
+                        resolved_value: Some(value.to_string()),
+                        resolved_from: Some("system-context".to_string()),
+                    };
+                }
+
+                if var.kind == "positional" || var.kind == "flag" {
+                    return VariableResolution {
+                        name: var.name.clone(),
+                        kind: var.kind.clone(),
+                        required: var.required,
+                        resolved_value: None,
+                        resolved_from: Some("cli-argument".to_string()),
+                    };
+                }
+
+                unmapped.push(var.name.clone());
+                VariableResolution {
+                    name: var.name.clone(),
+                    kind: var.kind.clone(),
+                    required: var.required,
+                    resolved_value: None,
+                    resolved_from: None,
+                }
+            })
+            .collect();
+
+        let unused_metadata = config
+            .metadata
+            .keys()
+            .filter(|key| !used_metadata.contains(*key))
+            .cloned()
+            .collect();
+
+        Ok(VariableReport {
+            prompt_id: resolved_id,
+            system: system.to_string(),
+            variables: resolutions,
+            unmapped,
+            unused_metadata,
+        })
     }
 
     fn render_body(
@@ -151,8 +451,11 @@ impl PromptRenderer {
         let template = self.env.template_from_str(&body)
             .with_context(|| format!("Failed to parse template from {}", source_path.display()))?;
 
-        // Create context for body template
-        let metadata: HashMap<String, String> = config.metadata.clone();
+        // Create context for body template, seeded with the workspace's
+        // `render.variables` config defaults and overridden by this
+        // prompt/system's own catalog metadata.
+        let mut metadata: HashMap<String, String> = self.context.config.render_defaults.variables.clone();
+        metadata.extend(config.metadata.clone());
 
         let rendered = template.render(context! {
             prompt => PromptValue::from(definition),
@@ -176,9 +479,27 @@ impl PromptRenderer {
         definition: &PromptDefinition,
         system_context: &SystemRenderContext,
         config: &SystemPromptConfig,
+        source: &str,
         body: &str,
         variables: &[VariableSpec],
+        diagnostics: &[VariableDiagnostic],
     ) -> Result<String> {
+        if !diagnostics.is_empty() {
+            let message = diagnostics
+                .iter()
+                .map(|d| {
+                    let (line, column) = line_col_at(source, d.byte_offset);
+                    format!("{}:{}: {}", line, column, d.message)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            anyhow::bail!(
+                "broken variable contract in '{}':\n{}",
+                definition.prompt_id,
+                message
+            );
+        }
+
         // Strip legacy title from body
         let body_cleaned = strip_legacy_title(body);
 
@@ -198,8 +519,10 @@ impl PromptRenderer {
             frontmatter.insert("argument-hint".to_string(), Value::from(argument_hint));
         }
 
-        // Build context for wrapper template
-        let metadata: HashMap<String, String> = config.metadata.clone();
+        // Build context for wrapper template, same `render.variables` +
+        // catalog-metadata precedence as `render_body`.
+        let mut metadata: HashMap<String, String> = self.context.config.render_defaults.variables.clone();
+        metadata.extend(config.metadata.clone());
 
         let rendered = template.render(context! {
             title => definition.title,
@@ -322,9 +645,74 @@ fn argument_hint_from_variables(variables: &[VariableSpec]) -> String {
     tokens.join(" ")
 }
 
+/// Every system key supported by at least one catalog prompt, used to tell
+/// a genuinely unknown system name apart from one this particular prompt
+/// just doesn't have a config for.
+fn known_systems() -> HashSet<&'static str> {
+    CATALOG
+        .values()
+        .flat_map(|definition| definition.systems.keys().map(String::as_str))
+        .collect()
+}
+
+fn format_suggestions(candidates: &[&str]) -> String {
+    candidates
+        .iter()
+        .map(|c| format!("'{}'", c))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn unknown_prompt_error(prompt_id: &str) -> anyhow::Error {
+    let candidates = find_suggestions(prompt_id, CATALOG.keys().map(String::as_str), 2, 3);
+    if candidates.is_empty() {
+        anyhow::anyhow!("Unknown prompt: {}", prompt_id)
+    } else {
+        anyhow::anyhow!(
+            "Unknown prompt: {}; did you mean {}?",
+            prompt_id,
+            format_suggestions(&candidates)
+        )
+    }
+}
+
+fn unknown_system_error(system: &str, known_systems: &HashSet<&'static str>) -> anyhow::Error {
+    let candidates = find_suggestions(system, known_systems.iter().copied(), 2, 3);
+    if candidates.is_empty() {
+        anyhow::anyhow!("Unknown system: {}", system)
+    } else {
+        anyhow::anyhow!(
+            "Unknown system: {}; did you mean {}?",
+            system,
+            format_suggestions(&candidates)
+        )
+    }
+}
+
 fn ensure_trailing_newline(mut content: String) -> String {
     if !content.ends_with('\n') {
         content.push('\n');
     }
     content
 }
+
+/// 1-indexed (line, column) of `byte_offset` within `source`, for rendering
+/// a caret-underlined diagnostic message.
+fn line_col_at(source: &str, byte_offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+
+    for (idx, ch) in source.char_indices() {
+        if idx >= byte_offset {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    (line, column)
+}
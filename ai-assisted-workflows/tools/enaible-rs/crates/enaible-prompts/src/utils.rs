@@ -1,6 +1,7 @@
 use regex::Regex;
 use once_cell::sync::Lazy;
 use serde::Serialize;
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct VariableSpec {
@@ -14,6 +15,32 @@ pub struct VariableSpec {
     pub repeatable: bool,
 }
 
+/// The kind of problem found while parsing a prompt's `@TOKEN{...}`
+/// declarations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// Two variables claim the same `positional $N` index.
+    DuplicatePositionalIndex,
+    /// Two variables claim the same `--flag-name`.
+    CollidingFlagName,
+    /// A declaration's description mentions both `positional` and `flag`.
+    AmbiguousKind,
+    /// `repeatable` was set on a kind that's never consumed as a list.
+    UnusedRepeatableMarker,
+}
+
+/// A problem found while parsing `@TOKEN{description}` declarations, with
+/// the byte offset of the offending match so callers can render a
+/// caret-underlined message pointing at the source.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariableDiagnostic {
+    pub kind: DiagnosticKind,
+    pub variable: String,
+    pub message: String,
+    pub byte_offset: usize,
+}
+
 static VARIABLE_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"@([A-Z_]+)(?:\{([^}]*)\})?").unwrap()
 });
@@ -26,14 +53,21 @@ static FLAG_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"--([a-z0-9-]+)").unwrap()
 });
 
-/// Extract variables from prompt content and return them along with the stripped content
-pub fn extract_variables(content: &str) -> (Vec<VariableSpec>, String) {
+/// Extract variables from prompt content, returning them alongside any
+/// `VariableDiagnostic`s found (duplicate positional indices, colliding flag
+/// names, ambiguous kind hints, unused `repeatable` markers) and the
+/// stripped content.
+pub fn extract_variables(content: &str) -> (Vec<VariableSpec>, Vec<VariableDiagnostic>, String) {
     let mut variables = Vec::new();
+    let mut diagnostics = Vec::new();
     let mut seen_names = std::collections::HashSet::new();
+    let mut seen_positional_indices: HashMap<usize, String> = HashMap::new();
+    let mut seen_flag_names: HashMap<String, String> = HashMap::new();
     let mut positional_counter = 1;
 
     // Extract all @VARIABLE{description} patterns
     for cap in VARIABLE_REGEX.captures_iter(content) {
+        let byte_offset = cap.get(0).unwrap().start();
         let name = format!("@{}", cap.get(1).unwrap().as_str());
 
         if seen_names.insert(name.clone()) {
@@ -43,7 +77,22 @@ pub fn extract_variables(content: &str) -> (Vec<VariableSpec>, String) {
             // Determine kind and other attributes from description
             let desc_lower = description_text.as_ref().map(|s| s.to_lowercase()).unwrap_or_default();
 
-            let (kind, flag_name, positional_index) = if desc_lower.contains("positional") || desc_lower.starts_with("$") {
+            let mentions_positional = desc_lower.contains("positional") || desc_lower.starts_with('$');
+            let mentions_flag = desc_lower.contains("flag") || desc_lower.starts_with("--");
+
+            if mentions_positional && mentions_flag {
+                diagnostics.push(VariableDiagnostic {
+                    kind: DiagnosticKind::AmbiguousKind,
+                    variable: name.clone(),
+                    message: format!(
+                        "{} declares both a positional and a flag hint; only the positional hint is used",
+                        name
+                    ),
+                    byte_offset,
+                });
+            }
+
+            let (kind, flag_name, positional_index) = if mentions_positional {
                 let idx = if let Some(cap) = POSITIONAL_REGEX.captures(&desc_lower) {
                     cap.get(1).and_then(|m| m.as_str().parse().ok())
                 } else {
@@ -52,7 +101,7 @@ pub fn extract_variables(content: &str) -> (Vec<VariableSpec>, String) {
                     Some(idx)
                 };
                 ("positional".to_string(), None, idx)
-            } else if desc_lower.contains("flag") || desc_lower.starts_with("--") {
+            } else if mentions_flag {
                 let flag = FLAG_REGEX.captures(&desc_lower)
                     .and_then(|c| c.get(1))
                     .map(|m| format!("--{}", m.as_str()));
@@ -63,9 +112,55 @@ pub fn extract_variables(content: &str) -> (Vec<VariableSpec>, String) {
                 ("config".to_string(), None, None)
             };
 
+            if let Some(index) = positional_index {
+                match seen_positional_indices.get(&index) {
+                    Some(existing) => diagnostics.push(VariableDiagnostic {
+                        kind: DiagnosticKind::DuplicatePositionalIndex,
+                        variable: name.clone(),
+                        message: format!(
+                            "{} claims positional index {} already used by {}",
+                            name, index, existing
+                        ),
+                        byte_offset,
+                    }),
+                    None => {
+                        seen_positional_indices.insert(index, name.clone());
+                    }
+                }
+            }
+
+            if let Some(flag) = &flag_name {
+                match seen_flag_names.get(flag) {
+                    Some(existing) => diagnostics.push(VariableDiagnostic {
+                        kind: DiagnosticKind::CollidingFlagName,
+                        variable: name.clone(),
+                        message: format!(
+                            "{} reuses flag name {} already claimed by {}",
+                            name, flag, existing
+                        ),
+                        byte_offset,
+                    }),
+                    None => {
+                        seen_flag_names.insert(flag.clone(), name.clone());
+                    }
+                }
+            }
+
             let required = !name.contains("OPTIONAL") && !desc_lower.contains("optional");
             let repeatable = desc_lower.contains("repeatable");
 
+            if repeatable && kind != "positional" && kind != "flag" {
+                diagnostics.push(VariableDiagnostic {
+                    kind: DiagnosticKind::UnusedRepeatableMarker,
+                    variable: name.clone(),
+                    message: format!(
+                        "{} is marked repeatable but its '{}' kind is never consumed as a list",
+                        name, kind
+                    ),
+                    byte_offset,
+                });
+            }
+
             variables.push(VariableSpec {
                 name: name.clone(),
                 type_text: description.clone().unwrap_or_default(),
@@ -79,10 +174,87 @@ pub fn extract_variables(content: &str) -> (Vec<VariableSpec>, String) {
         }
     }
 
-    // Strip the variable declarations from content
-    let stripped = VARIABLE_REGEX.replace_all(content, "").to_string();
+    // Strip the variable declarations from content. A single pass isn't
+    // idempotent: removing a match can juxtapose characters that weren't
+    // adjacent before and form a new one, e.g. "@@BAR{baz}FOO" has exactly
+    // one match ("@BAR{baz}"), but stripping it leaves "@FOO", itself a
+    // fresh match. Repeat until a fixed point so the stripped output never
+    // still matches `VARIABLE_REGEX`; each pass only removes non-empty
+    // matches, so this always terminates.
+    let mut stripped = content.to_string();
+    loop {
+        let next = VARIABLE_REGEX.replace_all(&stripped, "").to_string();
+        if next == stripped {
+            break;
+        }
+        stripped = next;
+    }
 
-    (variables, stripped)
+    (variables, diagnostics, stripped)
+}
+
+/// Levenshtein edit distance between two strings, used to power "did you
+/// mean?" suggestions the same way cargo resolves mistyped subcommands.
+pub fn lev_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+
+        for j in 1..=b.len() {
+            let prev_above = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = prev_above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Find the closest match to `query` among `candidates`, if any is within
+/// `max(candidate.len() / 3, min_threshold)` edits.
+pub fn find_suggestion<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    min_threshold: usize,
+) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, lev_distance(query, candidate)))
+        .filter(|(candidate, distance)| *distance <= min_threshold.max(candidate.len() / 3))
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Like `find_suggestion`, but keeps every candidate within threshold
+/// (closest first, capped at `limit`) instead of just the single best match
+/// — useful for "did you mean X or Y?" messages with more than one option.
+pub fn find_suggestions<'a>(
+    query: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    min_threshold: usize,
+    limit: usize,
+) -> Vec<&'a str> {
+    let mut scored: Vec<(&str, usize)> = candidates
+        .into_iter()
+        .map(|candidate| (candidate, lev_distance(query, candidate)))
+        .filter(|(candidate, distance)| *distance <= min_threshold.max(candidate.len() / 3))
+        .collect();
+
+    scored.sort_by_key(|(_, distance)| *distance);
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(candidate, _)| candidate)
+        .collect()
 }
 
 /// Parse comma-separated list or "all"
@@ -98,3 +270,93 @@ pub fn split_csv(value: &str) -> Vec<String> {
         .map(|s| s.to_string())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        /// Re-running `extract_variables` on its own stripped output must
+        /// never surface another `@TOKEN` — stripping is idempotent.
+        #[test]
+        fn stripped_output_has_no_further_variables(body in ".{0,200}") {
+            let (_, _, stripped) = extract_variables(&body);
+            let (further, _, _) = extract_variables(&stripped);
+            prop_assert!(further.is_empty());
+        }
+
+        /// A `positional $N` declaration always round-trips to that exact
+        /// index, regardless of the variable name or N chosen.
+        #[test]
+        fn positional_index_round_trips(
+            name in "[A-Z][A-Z_]{0,11}",
+            index in 1usize..1000,
+        ) {
+            let body = format!("@{}{{positional ${}}}", name, index);
+            let (variables, diagnostics, _) = extract_variables(&body);
+
+            prop_assert_eq!(variables.len(), 1);
+            prop_assert_eq!(variables[0].kind.as_str(), "positional");
+            prop_assert_eq!(variables[0].positional_index, Some(index));
+            prop_assert!(diagnostics.is_empty());
+        }
+
+        /// A `flag --name` declaration always round-trips to that exact
+        /// `--name`, regardless of the variable name chosen.
+        #[test]
+        fn flag_name_round_trips(
+            name in "[A-Z][A-Z_]{0,11}",
+            flag in "[a-z][a-z-]{0,11}",
+        ) {
+            let body = format!("@{}{{flag --{}}}", name, flag);
+            let (variables, diagnostics, _) = extract_variables(&body);
+
+            prop_assert_eq!(variables.len(), 1);
+            prop_assert_eq!(variables[0].kind.as_str(), "flag");
+            prop_assert_eq!(variables[0].flag_name.as_deref(), Some(format!("--{}", flag)).as_deref());
+            prop_assert!(diagnostics.is_empty());
+        }
+    }
+
+    #[test]
+    fn duplicate_positional_index_is_flagged() {
+        let (_, diagnostics, _) = extract_variables("@FOO{positional $1} @BAR{positional $1}");
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::DuplicatePositionalIndex));
+    }
+
+    #[test]
+    fn colliding_flag_name_is_flagged() {
+        let (_, diagnostics, _) = extract_variables("@FOO{flag --target} @BAR{flag --target}");
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::CollidingFlagName));
+    }
+
+    #[test]
+    fn ambiguous_kind_is_flagged() {
+        let (_, diagnostics, _) = extract_variables("@FOO{positional flag}");
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::AmbiguousKind));
+    }
+
+    #[test]
+    fn stripping_reaches_fixed_point_on_adjacency_forming_input() {
+        // "@BAR{baz}" is the only match; a single-pass strip would leave
+        // "@FOO" behind as a fresh, previously-nonexistent match.
+        let (_, _, stripped) = extract_variables("@@BAR{baz}FOO");
+        let (further, _, _) = extract_variables(&stripped);
+        assert!(further.is_empty());
+    }
+
+    #[test]
+    fn unused_repeatable_marker_is_flagged() {
+        let (_, diagnostics, _) = extract_variables("@FOO{repeatable internal}");
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::UnusedRepeatableMarker));
+    }
+}
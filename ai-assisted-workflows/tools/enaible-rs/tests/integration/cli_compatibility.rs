@@ -447,6 +447,103 @@ fn test_unknown_command_error() {
     assert!(!output.status.success(), "Unknown command should fail");
 }
 
+#[test]
+fn test_analyzers_cache_clear_command() {
+    let binary = get_binary_path();
+    let workspace = setup_test_workspace();
+
+    // Populate the cache with one run before clearing it.
+    Command::new(&binary)
+        .arg("analyzers")
+        .arg("run")
+        .arg("quality:lizard")
+        .arg("--target")
+        .arg(workspace.path())
+        .arg("--json")
+        .env("ENAIBLE_REPO_ROOT", workspace.path())
+        .output()
+        .expect("Failed to execute analyzers run command");
+
+    let output = Command::new(&binary)
+        .arg("analyzers")
+        .arg("cache")
+        .arg("clear")
+        .env("ENAIBLE_REPO_ROOT", workspace.path())
+        .output()
+        .expect("Failed to execute analyzers cache clear command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Cache cleared"));
+}
+
+#[test]
+fn test_analyzers_baseline_diff() {
+    let binary = get_binary_path();
+    let workspace = setup_test_workspace();
+    let baseline_path = workspace.path().join("baseline.json");
+
+    // First run writes the baseline. No baseline file exists yet, so its exit
+    // code reflects all of its findings; the stub analyzer reports none, so
+    // it should exit clean.
+    let write_output = Command::new(&binary)
+        .arg("analyzers")
+        .arg("run")
+        .arg("quality:lizard")
+        .arg("--target")
+        .arg(workspace.path())
+        .arg("--json")
+        .arg("--baseline")
+        .arg(&baseline_path)
+        .arg("--write-baseline")
+        .env("ENAIBLE_REPO_ROOT", workspace.path())
+        .output()
+        .expect("Failed to execute analyzers run --write-baseline");
+
+    assert!(baseline_path.is_file(), "--write-baseline should create the baseline file");
+    let baseline_json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&baseline_path).unwrap())
+            .expect("Baseline file should be valid JSON");
+    assert!(baseline_json.get("findings").is_some());
+    assert!(write_output.status.success());
+
+    // Second run against the same baseline should report no new findings.
+    let diff_output = Command::new(&binary)
+        .arg("analyzers")
+        .arg("run")
+        .arg("quality:lizard")
+        .arg("--target")
+        .arg(workspace.path())
+        .arg("--json")
+        .arg("--baseline")
+        .arg(&baseline_path)
+        .arg("--no-cache")
+        .env("ENAIBLE_REPO_ROOT", workspace.path())
+        .output()
+        .expect("Failed to execute analyzers run --baseline");
+
+    let stdout = String::from_utf8_lossy(&diff_output.stdout);
+    let response: serde_json::Value =
+        serde_json::from_str(&stdout).expect("Baseline-diff run should produce valid JSON");
+    assert_eq!(response["new_findings"].as_array().map(|a| a.len()), Some(0));
+}
+
+#[test]
+fn test_completions_command() {
+    let binary = get_binary_path();
+
+    let output = Command::new(&binary)
+        .arg("completions")
+        .arg("bash")
+        .output()
+        .expect("Failed to execute completions command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // A bash completion script registers the `enaible` completion function.
+    assert!(stdout.contains("enaible"));
+}
+
 #[test]
 fn test_prompts_render_unknown_prompt() {
     let binary = get_binary_path();